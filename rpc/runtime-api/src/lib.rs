@@ -0,0 +1,63 @@
+//! Runtime API definition to query logion Legal Officer Cases (LOC).
+//!
+//! Runtimes including `pallet-logion-loc` implement this API by delegating to the query functions
+//! of the pallet:
+//!
+//! ```ignore
+//! impl pallet_logion_loc_runtime_api::LogionLocApi<Block, AccountId, LocId, CollectionItemId, LegalOfficerCaseOf<Runtime>, CollectionItemOf<Runtime>, MetadataItemOf<Runtime>, FileOf<Runtime>, LocLinkOf<Runtime>, Hash> for Runtime {
+//! 	fn get_loc(loc_id: LocId) -> Option<LegalOfficerCaseOf<Runtime>> { LogionLoc::get_loc(loc_id) }
+//! 	...
+//! }
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait LogionLocApi<AccountId, LocId, CollectionItemId, Loc, CollectionItem, MetadataItem, File, LocLink, Hash> where
+		AccountId: Codec,
+		LocId: Codec,
+		CollectionItemId: Codec,
+		Loc: Codec,
+		CollectionItem: Codec,
+		MetadataItem: Codec,
+		File: Codec,
+		LocLink: Codec,
+		Hash: Codec,
+	{
+		/// Returns the LOC with given ID, if any.
+		fn get_loc(loc_id: LocId) -> Option<Loc>;
+
+		/// Returns the item of a collection LOC, if any.
+		fn get_collection_item(collection_loc_id: LocId, item_id: CollectionItemId) -> Option<CollectionItem>;
+
+		/// Returns at most `limit` IDs of the LOCs requested by given account, starting after `start_after` if any.
+		fn get_account_locs(account: AccountId, start_after: Option<LocId>, limit: u32) -> Vec<LocId>;
+
+		/// Returns at most `limit` IDs of the LOCs owned by given account, starting after `start_after` if any.
+		fn get_owner_locs(owner: AccountId, start_after: Option<LocId>, limit: u32) -> Vec<LocId>;
+
+		/// Returns at most `limit` IDs of the LOCs requested by given logion Identity LOC, starting after `start_after` if any.
+		fn get_identity_loc_locs(identity_loc_id: LocId, start_after: Option<LocId>, limit: u32) -> Vec<LocId>;
+
+		/// Returns the number of items in a collection LOC, if any.
+		fn get_collection_size(collection_loc_id: LocId) -> Option<u32>;
+
+		/// Returns the metadata items of given LOC, in the order they were added.
+		fn get_loc_metadata(loc_id: LocId) -> Vec<MetadataItem>;
+
+		/// Returns the files of given LOC, in the order they were added.
+		fn get_loc_files(loc_id: LocId) -> Vec<File>;
+
+		/// Returns the links of given LOC, in the order they were added.
+		fn get_loc_links(loc_id: LocId) -> Vec<LocLink>;
+
+		/// Returns the LOCs and collection items (if any) which include a file with given hash.
+		fn find_by_file_hash(hash: Hash) -> Vec<(LocId, Option<CollectionItemId>)>;
+
+		/// Returns the IDs of the LOCs linking to given LOC or using it as terms and conditions.
+		fn get_linked_from(loc_id: LocId) -> Vec<LocId>;
+	}
+}
@@ -0,0 +1,194 @@
+//! RPC interface to query logion Legal Officer Cases (LOC).
+//!
+//! The methods of this interface delegate to the `LogionLocApi` runtime API and return
+//! their results as JSON.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_logion_loc_runtime_api::LogionLocApi as LogionLocRuntimeApi;
+
+#[cfg(test)]
+mod tests;
+
+#[rpc(client, server)]
+pub trait LogionLocApi<BlockHash, AccountId, LocId, CollectionItemId, Loc, CollectionItem, MetadataItem, File, LocLink, Hash> {
+	/// Returns the LOC with given ID, if any.
+	#[method(name = "loc_getLoc")]
+	fn get_loc(&self, loc_id: LocId, at: Option<BlockHash>) -> RpcResult<Option<Loc>>;
+
+	/// Returns the item of a collection LOC, if any.
+	#[method(name = "loc_getCollectionItem")]
+	fn get_collection_item(&self, collection_loc_id: LocId, item_id: CollectionItemId, at: Option<BlockHash>) -> RpcResult<Option<CollectionItem>>;
+
+	/// Returns at most `limit` IDs of the LOCs requested by given account, starting after `start_after` if any.
+	#[method(name = "loc_getAccountLocs")]
+	fn get_account_locs(&self, account: AccountId, start_after: Option<LocId>, limit: u32, at: Option<BlockHash>) -> RpcResult<Vec<LocId>>;
+
+	/// Returns at most `limit` IDs of the LOCs owned by given account, starting after `start_after` if any.
+	#[method(name = "loc_getOwnerLocs")]
+	fn get_owner_locs(&self, owner: AccountId, start_after: Option<LocId>, limit: u32, at: Option<BlockHash>) -> RpcResult<Vec<LocId>>;
+
+	/// Returns at most `limit` IDs of the LOCs requested by given logion Identity LOC, starting after `start_after` if any.
+	#[method(name = "loc_getIdentityLocLocs")]
+	fn get_identity_loc_locs(&self, identity_loc_id: LocId, start_after: Option<LocId>, limit: u32, at: Option<BlockHash>) -> RpcResult<Vec<LocId>>;
+
+	/// Returns the number of items in a collection LOC, if any.
+	#[method(name = "loc_getCollectionSize")]
+	fn get_collection_size(&self, collection_loc_id: LocId, at: Option<BlockHash>) -> RpcResult<Option<u32>>;
+
+	/// Returns the metadata items of given LOC, in the order they were added.
+	#[method(name = "loc_getLocMetadata")]
+	fn get_loc_metadata(&self, loc_id: LocId, at: Option<BlockHash>) -> RpcResult<Vec<MetadataItem>>;
+
+	/// Returns the files of given LOC, in the order they were added.
+	#[method(name = "loc_getLocFiles")]
+	fn get_loc_files(&self, loc_id: LocId, at: Option<BlockHash>) -> RpcResult<Vec<File>>;
+
+	/// Returns the links of given LOC, in the order they were added.
+	#[method(name = "loc_getLocLinks")]
+	fn get_loc_links(&self, loc_id: LocId, at: Option<BlockHash>) -> RpcResult<Vec<LocLink>>;
+
+	/// Returns the LOCs and collection items (if any) which include a file with given hash.
+	#[method(name = "loc_findByFileHash")]
+	fn find_by_file_hash(&self, hash: Hash, at: Option<BlockHash>) -> RpcResult<Vec<(LocId, Option<CollectionItemId>)>>;
+
+	/// Returns the IDs of the LOCs linking to given LOC or using it as terms and conditions.
+	#[method(name = "loc_getLinkedFrom")]
+	fn get_linked_from(&self, loc_id: LocId, at: Option<BlockHash>) -> RpcResult<Vec<LocId>>;
+}
+
+/// Provides RPC methods to query LOCs.
+pub struct LogionLoc<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> LogionLoc<C, Block> {
+	/// Creates a new instance of the LOC RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error code for runtime API call failures.
+const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error(error: impl ToString, message: &'static str) -> CallError {
+	CallError::Custom(ErrorObject::owned(RUNTIME_ERROR, message, Some(error.to_string())))
+}
+
+impl<C, Block, AccountId, LocId, CollectionItemId, Loc, CollectionItem, MetadataItem, File, LocLink, Hash>
+	LogionLocApiServer<<Block as BlockT>::Hash, AccountId, LocId, CollectionItemId, Loc, CollectionItem, MetadataItem, File, LocLink, Hash>
+	for LogionLoc<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: LogionLocRuntimeApi<Block, AccountId, LocId, CollectionItemId, Loc, CollectionItem, MetadataItem, File, LocLink, Hash>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	LocId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	CollectionItemId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Loc: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	CollectionItem: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	MetadataItem: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	File: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	LocLink: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Hash: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn get_loc(&self, loc_id: LocId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Option<Loc>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+		api.get_loc(&at, loc_id)
+			.map_err(|e| runtime_error(e, "Unable to query LOC.").into())
+	}
+
+	fn get_collection_item(&self, collection_loc_id: LocId, item_id: CollectionItemId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Option<CollectionItem>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+		api.get_collection_item(&at, collection_loc_id, item_id)
+			.map_err(|e| runtime_error(e, "Unable to query collection item.").into())
+	}
+
+	fn get_account_locs(&self, account: AccountId, start_after: Option<LocId>, limit: u32, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<LocId>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+		api.get_account_locs(&at, account, start_after, limit)
+			.map_err(|e| runtime_error(e, "Unable to query account LOCs.").into())
+	}
+
+	fn get_owner_locs(&self, owner: AccountId, start_after: Option<LocId>, limit: u32, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<LocId>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+		api.get_owner_locs(&at, owner, start_after, limit)
+			.map_err(|e| runtime_error(e, "Unable to query owner LOCs.").into())
+	}
+
+	fn get_identity_loc_locs(&self, identity_loc_id: LocId, start_after: Option<LocId>, limit: u32, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<LocId>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+		api.get_identity_loc_locs(&at, identity_loc_id, start_after, limit)
+			.map_err(|e| runtime_error(e, "Unable to query identity LOC LOCs.").into())
+	}
+
+	fn get_collection_size(&self, collection_loc_id: LocId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Option<u32>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+		api.get_collection_size(&at, collection_loc_id)
+			.map_err(|e| runtime_error(e, "Unable to query collection size.").into())
+	}
+
+	fn get_loc_metadata(&self, loc_id: LocId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<MetadataItem>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+		api.get_loc_metadata(&at, loc_id)
+			.map_err(|e| runtime_error(e, "Unable to query LOC metadata.").into())
+	}
+
+	fn get_loc_files(&self, loc_id: LocId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<File>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+		api.get_loc_files(&at, loc_id)
+			.map_err(|e| runtime_error(e, "Unable to query LOC files.").into())
+	}
+
+	fn get_loc_links(&self, loc_id: LocId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<LocLink>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+		api.get_loc_links(&at, loc_id)
+			.map_err(|e| runtime_error(e, "Unable to query LOC links.").into())
+	}
+
+	fn find_by_file_hash(&self, hash: Hash, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<(LocId, Option<CollectionItemId>)>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+		api.find_by_file_hash(&at, hash)
+			.map_err(|e| runtime_error(e, "Unable to query LOCs by file hash.").into())
+	}
+
+	fn get_linked_from(&self, loc_id: LocId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<LocId>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+		api.get_linked_from(&at, loc_id)
+			.map_err(|e| runtime_error(e, "Unable to query linking LOCs.").into())
+	}
+}
+
+impl<C, Block> LogionLoc<C, Block>
+where
+	Block: BlockT,
+	C: HeaderBackend<Block>,
+{
+	fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block> {
+		BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+	}
+}
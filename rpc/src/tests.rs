@@ -0,0 +1,173 @@
+use super::*;
+
+use sp_api::ApiRef;
+use sp_blockchain::{BlockStatus, Info};
+use sp_runtime::testing::{Block as TestBlock, ExtrinsicWrapper, Header};
+use sp_runtime::traits::NumberFor;
+
+type Block = TestBlock<ExtrinsicWrapper<u64>>;
+type Hash = <Block as BlockT>::Hash;
+
+const LOC_ID: u32 = 1;
+const COLLECTION_LOC_ID: u32 = 2;
+const ITEM_ID: u32 = 3;
+const REQUESTER: u64 = 4;
+const OWNER: u64 = 5;
+const FILE_HASH: u64 = 6;
+
+#[derive(Clone, Default)]
+struct MockApi;
+
+sp_api::mock_impl_runtime_apis! {
+	impl LogionLocRuntimeApi<Block, u64, u32, u32, String, String, String, String, String, u64> for MockApi {
+		fn get_loc(loc_id: u32) -> Option<String> {
+			if loc_id == LOC_ID { Some("loc".into()) } else { None }
+		}
+
+		fn get_collection_item(collection_loc_id: u32, item_id: u32) -> Option<String> {
+			if collection_loc_id == COLLECTION_LOC_ID && item_id == ITEM_ID { Some("item".into()) } else { None }
+		}
+
+		fn get_account_locs(account: u64, _start_after: Option<u32>, limit: u32) -> Vec<u32> {
+			if account == REQUESTER { vec![LOC_ID, COLLECTION_LOC_ID].into_iter().take(limit as usize).collect() } else { Vec::new() }
+		}
+
+		fn get_owner_locs(owner: u64, start_after: Option<u32>, limit: u32) -> Vec<u32> {
+			if owner == OWNER {
+				vec![LOC_ID, COLLECTION_LOC_ID].into_iter()
+					.filter(|loc_id| start_after.map_or(true, |last| *loc_id > last))
+					.take(limit as usize)
+					.collect()
+			} else {
+				Vec::new()
+			}
+		}
+
+		fn get_identity_loc_locs(_identity_loc_id: u32, _start_after: Option<u32>, _limit: u32) -> Vec<u32> {
+			Vec::new()
+		}
+
+		fn get_collection_size(collection_loc_id: u32) -> Option<u32> {
+			if collection_loc_id == COLLECTION_LOC_ID { Some(1) } else { None }
+		}
+
+		fn get_loc_metadata(loc_id: u32) -> Vec<String> {
+			if loc_id == LOC_ID { vec!["metadata".into(), "metadata".into()] } else { Vec::new() }
+		}
+
+		fn get_loc_files(loc_id: u32) -> Vec<String> {
+			if loc_id == LOC_ID { vec!["file".into()] } else { Vec::new() }
+		}
+
+		fn get_loc_links(loc_id: u32) -> Vec<String> {
+			if loc_id == LOC_ID { vec!["link".into()] } else { Vec::new() }
+		}
+
+		fn find_by_file_hash(hash: u64) -> Vec<(u32, Option<u32>)> {
+			if hash == FILE_HASH { vec![(LOC_ID, None), (COLLECTION_LOC_ID, Some(ITEM_ID))] } else { Vec::new() }
+		}
+
+		fn get_linked_from(loc_id: u32) -> Vec<u32> {
+			if loc_id == LOC_ID { vec![COLLECTION_LOC_ID] } else { Vec::new() }
+		}
+	}
+}
+
+struct MockClient;
+
+impl ProvideRuntimeApi<Block> for MockClient {
+	type Api = MockApi;
+
+	fn runtime_api(&self) -> ApiRef<'_, Self::Api> {
+		MockApi.into()
+	}
+}
+
+impl HeaderBackend<Block> for MockClient {
+	fn header(&self, _id: BlockId<Block>) -> sp_blockchain::Result<Option<Header>> {
+		Ok(None)
+	}
+
+	fn info(&self) -> Info<Block> {
+		Info {
+			best_hash: Hash::default(),
+			best_number: 0,
+			genesis_hash: Hash::default(),
+			finalized_hash: Hash::default(),
+			finalized_number: 0,
+			finalized_state: None,
+			number_leaves: 0,
+			block_gap: None,
+		}
+	}
+
+	fn status(&self, _id: BlockId<Block>) -> sp_blockchain::Result<BlockStatus> {
+		Ok(BlockStatus::InChain)
+	}
+
+	fn number(&self, _hash: Hash) -> sp_blockchain::Result<Option<NumberFor<Block>>> {
+		Ok(None)
+	}
+
+	fn hash(&self, _number: NumberFor<Block>) -> sp_blockchain::Result<Option<Hash>> {
+		Ok(None)
+	}
+}
+
+fn rpc() -> LogionLoc<MockClient, Block> {
+	LogionLoc::new(Arc::new(MockClient))
+}
+
+#[test]
+fn it_gets_loc() {
+	let at = Some(Hash::default());
+	assert_eq!(rpc().get_loc(LOC_ID, at).unwrap(), Some("loc".to_string()));
+	assert_eq!(rpc().get_loc(COLLECTION_LOC_ID, at).unwrap(), None);
+}
+
+#[test]
+fn it_gets_loc_at_best_block() {
+	assert_eq!(rpc().get_loc(LOC_ID, None).unwrap(), Some("loc".to_string()));
+}
+
+#[test]
+fn it_gets_collection_item() {
+	let at = Some(Hash::default());
+	assert_eq!(rpc().get_collection_item(COLLECTION_LOC_ID, ITEM_ID, at).unwrap(), Some("item".to_string()));
+	assert_eq!(rpc().get_collection_item(LOC_ID, ITEM_ID, at).unwrap(), None);
+	assert_eq!(rpc().get_collection_size(COLLECTION_LOC_ID, at).unwrap(), Some(1));
+}
+
+#[test]
+fn it_gets_account_locs_as_json() {
+	let at = Some(Hash::default());
+	let locs = rpc().get_account_locs(REQUESTER, None, 10, at).unwrap();
+	assert_eq!(serde_json::to_string(&locs).unwrap(), "[1,2]");
+	assert!(rpc().get_identity_loc_locs(LOC_ID, None, 10, at).unwrap().is_empty());
+}
+
+#[test]
+fn it_gets_owner_locs_by_page() {
+	let at = Some(Hash::default());
+	assert_eq!(rpc().get_owner_locs(OWNER, None, 1, at).unwrap(), vec![LOC_ID]);
+	assert_eq!(rpc().get_owner_locs(OWNER, Some(LOC_ID), 1, at).unwrap(), vec![COLLECTION_LOC_ID]);
+	assert!(rpc().get_owner_locs(REQUESTER, None, 1, at).unwrap().is_empty());
+}
+
+#[test]
+fn it_gets_loc_items() {
+	let at = Some(Hash::default());
+	assert_eq!(rpc().get_loc_metadata(LOC_ID, at).unwrap(), vec!["metadata".to_string(), "metadata".to_string()]);
+	assert_eq!(rpc().get_loc_files(LOC_ID, at).unwrap(), vec!["file".to_string()]);
+	assert_eq!(rpc().get_loc_links(LOC_ID, at).unwrap(), vec!["link".to_string()]);
+	assert!(rpc().get_loc_files(COLLECTION_LOC_ID, at).unwrap().is_empty());
+}
+
+#[test]
+fn it_finds_locs_by_file_hash_and_link() {
+	let at = Some(Hash::default());
+	let found = rpc().find_by_file_hash(FILE_HASH, at).unwrap();
+	assert_eq!(serde_json::to_string(&found).unwrap(), "[[1,null],[2,3]]");
+	assert!(rpc().find_by_file_hash(FILE_HASH + 1, at).unwrap().is_empty());
+	assert_eq!(rpc().get_linked_from(LOC_ID, at).unwrap(), vec![COLLECTION_LOC_ID]);
+}
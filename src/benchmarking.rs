@@ -1,7 +1,7 @@
 use super::*;
 
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
-use frame_support::{assert_ok, traits::EnsureOrigin};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{assert_ok, sp_runtime::traits::Bounded, traits::EnsureOrigin};
 use frame_system::RawOrigin;
 use sp_std::{vec, vec::Vec, boxed::Box};
 
@@ -10,19 +10,21 @@ use crate::Config;
 
 benchmarks! {
 	create_polkadot_identity_loc {
-		let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
-	}: _(caller, Default::default(), Default::default())
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
+	}: _(caller, Default::default(), requester)
 
 	create_logion_identity_loc {
-		let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
+		let caller = funded_origin::<T>();
 	}: _(caller, Default::default())
 
 	create_polkadot_transaction_loc {
-		let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
-	}: _(caller, Default::default(), Default::default())
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
+	}: _(caller, Default::default(), requester)
 
 	create_logion_transaction_loc {
-		let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
+		let caller = funded_origin::<T>();
 		let identity_loc_id = into_loc_id::<T>(0);
 		assert_ok!(LogionLoc::<T>::create_logion_identity_loc(caller.clone().into(), identity_loc_id));
 		assert_ok!(LogionLoc::<T>::close(caller.clone().into(), identity_loc_id));
@@ -30,76 +32,408 @@ benchmarks! {
 	}: _(caller, loc_id, identity_loc_id)
 
 	add_metadata {
-		let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
+		let v in 0 .. T::MaxMetadataItemValueSize::get();
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
 		let loc_id = Default::default();
-		let item = MetadataItem {
+		let item = MetadataItemParams {
 			name: vec![1u8, 2u8, 3u8],
-			value: vec![4u8, 5u8, 6u8],
-			submitter: Default::default(),
+			value: vec![4u8; v as usize],
+			submitter: requester.clone(),
 		};
-		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, Default::default()));
+		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, requester.clone()));
+		add_metadata_items::<T>(&caller, loc_id, T::MaxLocMetadata::get() - 1);
 	}: _(caller, loc_id, item)
 
 	add_file {
-		let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
 		let loc_id = Default::default();
-		let file = File {
+		let file = FileParams {
 			hash: Default::default(),
 			nature: vec![1u8, 2u8, 3u8],
-			submitter: Default::default(),
+			submitter: requester.clone(),
 		};
-		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, Default::default()));
+		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, requester.clone()));
+		add_files::<T>(&caller, loc_id, T::MaxLocFiles::get() - 1);
 	}: _(caller, loc_id, file)
 
 	add_link {
-		let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
 		let linked_loc_id = into_loc_id::<T>(0);
-		let link = LocLink {
+		let link = LocLinkParams {
 			id: linked_loc_id.clone(),
 			nature: vec![1u8, 2u8, 3u8],
 		};
-		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), linked_loc_id, Default::default()));
+		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), linked_loc_id, requester.clone()));
 		let loc_id = into_loc_id::<T>(1);
-		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, Default::default()));
+		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, requester.clone()));
+		add_links::<T>(&caller, loc_id, T::MaxLocLinks::get() - 1);
 	}: _(caller, loc_id, link)
 
+	delete_metadata {
+		let n in 1 .. T::MaxLocMetadata::get();
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
+		let loc_id = Default::default();
+		let name = vec![1u8, 2u8, 3u8];
+		let item = MetadataItemParams {
+			name: name.clone(),
+			value: vec![4u8, 5u8, 6u8],
+			submitter: requester.clone(),
+		};
+		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, requester.clone()));
+		assert_ok!(LogionLoc::<T>::add_metadata(caller.clone().into(), loc_id, item));
+		add_metadata_items::<T>(&caller, loc_id, n - 1);
+	}: _(caller, loc_id, name)
+
+	delete_file {
+		let n in 1 .. T::MaxLocFiles::get();
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
+		let loc_id = Default::default();
+		let hash: <T as Config>::Hash = Default::default();
+		let file = FileParams {
+			hash: hash.clone(),
+			nature: vec![1u8, 2u8, 3u8],
+			submitter: requester.clone(),
+		};
+		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, requester.clone()));
+		assert_ok!(LogionLoc::<T>::add_file(caller.clone().into(), loc_id, file));
+		add_files::<T>(&caller, loc_id, n - 1);
+	}: _(caller, loc_id, hash)
+
+	delete_link {
+		let n in 1 .. T::MaxLocLinks::get();
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
+		let linked_loc_id = into_loc_id::<T>(0);
+		let link = LocLinkParams {
+			id: linked_loc_id.clone(),
+			nature: vec![1u8, 2u8, 3u8],
+		};
+		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), linked_loc_id, requester.clone()));
+		let loc_id = into_loc_id::<T>(1);
+		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, requester.clone()));
+		assert_ok!(LogionLoc::<T>::add_link(caller.clone().into(), loc_id, link));
+		add_links::<T>(&caller, loc_id, n - 1);
+	}: _(caller, loc_id, linked_loc_id)
+
+	acknowledge_metadata {
+		let n in 1 .. T::MaxLocMetadata::get();
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
+		let loc_id = Default::default();
+		let name = vec![1u8, 2u8, 3u8];
+		let item = MetadataItemParams {
+			name: name.clone(),
+			value: vec![4u8, 5u8, 6u8],
+			submitter: requester.clone(),
+		};
+		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, requester.clone()));
+		add_metadata_items::<T>(&caller, loc_id, n - 1);
+		assert_ok!(LogionLoc::<T>::add_metadata(RawOrigin::Signed(requester).into(), loc_id, item));
+	}: _(caller, loc_id, name)
+
+	acknowledge_file {
+		let n in 1 .. T::MaxLocFiles::get();
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
+		let loc_id = Default::default();
+		let hash: <T as Config>::Hash = Default::default();
+		let file = FileParams {
+			hash: hash.clone(),
+			nature: vec![1u8, 2u8, 3u8],
+			submitter: requester.clone(),
+		};
+		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, requester.clone()));
+		add_files::<T>(&caller, loc_id, n - 1);
+		assert_ok!(LogionLoc::<T>::add_file(RawOrigin::Signed(requester).into(), loc_id, file));
+	}: _(caller, loc_id, hash)
+
 	close {
-		let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
+		let n in 0 .. T::MaxLocMetadata::get() + T::MaxLocFiles::get();
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
 		let loc_id = Default::default();
-		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, Default::default()));
+		// Closing a Polkadot Identity LOC indexes it as a closed identity LOC
+		assert_ok!(LogionLoc::<T>::create_polkadot_identity_loc(caller.clone().into(), loc_id, requester.clone()));
+		add_loc_items::<T>(&caller, loc_id, n);
 	}: _(caller, loc_id)
 
-	make_void {
-		let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
+	close_and_seal {
+		let n in 0 .. T::MaxLocMetadata::get() + T::MaxLocFiles::get();
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
 		let loc_id = Default::default();
-		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, Default::default()));
+		// Closing a Polkadot Identity LOC indexes it as a closed identity LOC
+		assert_ok!(LogionLoc::<T>::create_polkadot_identity_loc(caller.clone().into(), loc_id, requester.clone()));
+		add_loc_items::<T>(&caller, loc_id, n);
+	}: _(caller, loc_id, into_hash::<T>(0))
+
+	make_void {
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
+		let loc_id = into_loc_id::<T>(1);
+		create_closed_identity_locs::<T>(&caller, &requester, loc_id);
 	}: _(caller, loc_id)
 
 	make_void_and_replace {
-		let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
-		let replacer_loc_id = into_loc_id::<T>(0);
-		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), replacer_loc_id, Default::default()));
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
 		let loc_id = into_loc_id::<T>(1);
-		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, Default::default()));
+		create_closed_identity_locs::<T>(&caller, &requester, loc_id);
+		let replacer_loc_id = into_loc_id::<T>(2);
+		assert_ok!(LogionLoc::<T>::create_polkadot_identity_loc(caller.clone().into(), replacer_loc_id, requester.clone()));
 	}: _(caller, loc_id, replacer_loc_id)
 
 	create_collection_loc {
-		let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
-	}: _(caller, Default::default(), Default::default(), Option::None, Option::Some(1))
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
+	}: _(caller, Default::default(), requester, Option::None, Option::Some(1), false)
 
 	add_collection_item {
-		let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
+		let f in 1 .. T::MaxCollectionItemFiles::get();
+		let caller = funded_origin::<T>();
+		let loc_id = into_loc_id::<T>(0);
+		let requester = funded_requester::<T>();
+		assert_ok!(LogionLoc::<T>::create_collection_loc(caller.clone().into(), loc_id, requester.clone(), Option::None, Option::Some(1), true));
+		assert_ok!(LogionLoc::<T>::close(caller.clone().into(), loc_id));
+		let files = collection_item_files::<T>(f);
+	}: _(RawOrigin::Signed(requester), loc_id, Default::default(), Default::default(), files, Option::None, false)
+
+	add_collection_item_with_terms_and_conditions {
+		let f in 1 .. T::MaxCollectionItemFiles::get();
+		let t in 0 .. T::MaxCollectionItemTermsAndConditions::get();
+		let caller = funded_origin::<T>();
 		let loc_id = into_loc_id::<T>(0);
-		let requester: T::AccountId = whitelisted_caller();
-		assert_ok!(LogionLoc::<T>::create_collection_loc(caller.clone().into(), loc_id, requester.clone(), Option::None, Option::Some(1)));
+		let requester = funded_requester::<T>();
+		assert_ok!(LogionLoc::<T>::create_collection_loc(caller.clone().into(), loc_id, requester.clone(), Option::None, Option::Some(1), true));
 		assert_ok!(LogionLoc::<T>::close(caller.clone().into(), loc_id));
-	}: _(RawOrigin::Signed(requester), loc_id, Default::default(), Default::default())
+		let files = collection_item_files::<T>(f);
+		let terms_and_conditions = terms_and_conditions::<T>(&caller, t);
+	}: _(RawOrigin::Signed(requester), loc_id, Default::default(), Default::default(), files, Option::None, false, terms_and_conditions)
+
+	make_item_void {
+		let caller = funded_origin::<T>();
+		let loc_id = into_loc_id::<T>(0);
+		let requester = funded_requester::<T>();
+		let item_id = into_item_id::<T>(0);
+		let replacer_item_id = into_item_id::<T>(1);
+		assert_ok!(LogionLoc::<T>::create_collection_loc(caller.clone().into(), loc_id, requester.clone(), Option::None, Option::Some(2), false));
+		assert_ok!(LogionLoc::<T>::close(caller.clone().into(), loc_id));
+		assert_ok!(LogionLoc::<T>::add_collection_item(RawOrigin::Signed(requester.clone()).into(), loc_id, item_id, Default::default(), Vec::new(), Option::None, false));
+		assert_ok!(LogionLoc::<T>::add_collection_item(RawOrigin::Signed(requester.clone()).into(), loc_id, replacer_item_id, Default::default(), Vec::new(), Option::None, false));
+	}: _(caller, loc_id, item_id, Some(replacer_item_id))
+
+	add_collection_items {
+		let n in 1 .. T::MaxItemsPerBatch::get();
+		let f in 0 .. T::MaxItemsPerBatch::get() * T::MaxCollectionItemFiles::get();
+		let t in 0 .. T::MaxItemsPerBatch::get() * T::MaxCollectionItemTermsAndConditions::get();
+		let caller = funded_origin::<T>();
+		let loc_id = into_loc_id::<T>(0);
+		let requester = funded_requester::<T>();
+		assert_ok!(LogionLoc::<T>::create_collection_loc(caller.clone().into(), loc_id, requester.clone(), Option::None, Option::Some(n), f > 0));
+		assert_ok!(LogionLoc::<T>::close(caller.clone().into(), loc_id));
+		// Items of a collection accepting uploads must have at least one file
+		let files_per_item = spread(if f > 0 { f.max(n) } else { 0 }, n, T::MaxCollectionItemFiles::get());
+		let terms_and_conditions_per_item = spread(t, n, T::MaxCollectionItemTermsAndConditions::get());
+		let all_terms_and_conditions = terms_and_conditions::<T>(&caller, T::MaxCollectionItemTermsAndConditions::get());
+		let items: Vec<CollectionItemParamsOf<T>> = (0..n).map(|i| CollectionItemParams {
+			id: into_item_id::<T>(i as u8),
+			description: vec![1u8, 2u8, 3u8],
+			files: collection_item_files::<T>(files_per_item[i as usize]),
+			token: Option::None,
+			restricted_delivery: false,
+			terms_and_conditions: all_terms_and_conditions[..terms_and_conditions_per_item[i as usize] as usize].to_vec(),
+		}).collect();
+		let items: BoundedVec<CollectionItemParamsOf<T>, T::MaxItemsPerBatch> = items.try_into().unwrap();
+	}: _(RawOrigin::Signed(requester), loc_id, items)
+
+	update_collection_item {
+		let f in 1 .. T::MaxCollectionItemFiles::get();
+		let t in 0 .. T::MaxCollectionItemTermsAndConditions::get();
+		let caller = funded_origin::<T>();
+		let loc_id = into_loc_id::<T>(0);
+		let requester = funded_requester::<T>();
+		let item_id: T::CollectionItemId = Default::default();
+		assert_ok!(LogionLoc::<T>::create_collection_loc(caller.clone().into(), loc_id, requester.clone(), Option::None, Option::Some(1), true));
+		assert_ok!(LogionLoc::<T>::close(caller.clone().into(), loc_id));
+		// Unindexing the files and terms and conditions of the previous revision is charged by
+		// `Pallet::previous_revision_weight`, the previous revision has a single file
+		assert_ok!(LogionLoc::<T>::add_collection_item(RawOrigin::Signed(requester.clone()).into(), loc_id, item_id, Default::default(), collection_item_files::<T>(1), Option::None, false));
+		let files = collection_item_files::<T>(f);
+		let terms_and_conditions = terms_and_conditions::<T>(&caller, t);
+	}: _(RawOrigin::Signed(requester), loc_id, item_id, vec![1u8, 2u8, 3u8], files, Option::None, false, terms_and_conditions)
+
+	transfer_ownership {
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
+		let loc_id = Default::default();
+		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, requester));
+		let previous_owner = hand_over_locs::<T>(&[loc_id]);
+		let new_owner = signer::<T>(&caller);
+	}: _(RawOrigin::Signed(previous_owner), loc_id, new_owner)
+
+	transfer_all_ownership {
+		let n in 1 .. 100;
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
+		let loc_ids: Vec<T::LocId> = (0..n).map(|i| into_loc_id::<T>(i as u128)).collect();
+		for loc_id in loc_ids.iter() {
+			assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), *loc_id, requester.clone()));
+		}
+		let previous_owner = hand_over_locs::<T>(&loc_ids);
+		let new_owner = signer::<T>(&caller);
+	}: _(RawOrigin::Signed(previous_owner.clone()), previous_owner, new_owner, n)
+
+	pay_loc_fee {
+		let caller = funded_origin::<T>();
+		let requester = funded_requester::<T>();
+		let loc_id = Default::default();
+		assert_ok!(LogionLoc::<T>::create_collection_loc(caller.clone().into(), loc_id, requester.clone(), Option::None, Option::Some(1), false));
+		// The fee may be zero in the runtime, in which case none is due
+		LocFees::<T>::insert(loc_id, T::CollectionLocFee::get().max(T::Currency::minimum_balance()));
+	}: _(RawOrigin::Signed(requester), loc_id)
+}
+
+fn funded_origin<T: pallet::Config>() -> RawOrigin<T::AccountId> {
+	let origin: RawOrigin<T::AccountId> = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
+	if let RawOrigin::Signed(account) = &origin {
+		fund::<T>(account);
+	}
+	origin
+}
+
+fn signer<T: pallet::Config>(origin: &RawOrigin<T::AccountId>) -> T::AccountId {
+	match origin {
+		RawOrigin::Signed(account) => account.clone(),
+		_ => panic!("Expected a signed origin"),
+	}
+}
+
+fn fund<T: pallet::Config>(account: &T::AccountId) {
+	T::Currency::make_free_balance_be(account, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+/// Moves given LOCs and their deposits to a funded account distinct from the legal officer of `CreateOrigin`, so that
+/// they can be transferred back to the only account known to be a legal officer.
+fn hand_over_locs<T: pallet::Config>(loc_ids: &[T::LocId]) -> T::AccountId {
+	let previous_owner: T::AccountId = account("previous_owner", 0, 0);
+	fund::<T>(&previous_owner);
+	for loc_id in loc_ids.iter() {
+		let loc = LocMap::<T>::get(loc_id).unwrap();
+		assert_ok!(LogionLoc::<T>::do_transfer_ownership(loc_id, loc, &previous_owner));
+	}
+	previous_owner
+}
+
+fn funded_requester<T: pallet::Config>() -> T::AccountId {
+	let requester: T::AccountId = whitelisted_caller();
+	fund::<T>(&requester);
+	requester
+}
+
+/// Adds acknowledged metadata items with a value of maximum size to given LOC.
+fn add_metadata_items<T: pallet::Config>(origin: &RawOrigin<T::AccountId>, loc_id: T::LocId, count: u32) {
+	let submitter = signer::<T>(origin);
+	for i in 0..count {
+		let item = MetadataItemParams {
+			name: i.encode(),
+			value: vec![0u8; T::MaxMetadataItemValueSize::get() as usize],
+			submitter: submitter.clone(),
+		};
+		assert_ok!(LogionLoc::<T>::add_metadata(origin.clone().into(), loc_id, item));
+	}
+}
+
+/// Adds acknowledged files to given LOC.
+fn add_files<T: pallet::Config>(origin: &RawOrigin<T::AccountId>, loc_id: T::LocId, count: u32) {
+	let submitter = signer::<T>(origin);
+	for i in 0..count {
+		let file = FileParams {
+			hash: into_hash::<T>(i + 1),
+			nature: vec![1u8, 2u8, 3u8],
+			submitter: submitter.clone(),
+		};
+		assert_ok!(LogionLoc::<T>::add_file(origin.clone().into(), loc_id, file));
+	}
+}
+
+/// Adds `count` acknowledged items to given LOC, metadata items first then files.
+fn add_loc_items<T: pallet::Config>(origin: &RawOrigin<T::AccountId>, loc_id: T::LocId, count: u32) {
+	let metadata_items = count.min(T::MaxLocMetadata::get());
+	add_metadata_items::<T>(origin, loc_id, metadata_items);
+	add_files::<T>(origin, loc_id, count - metadata_items);
+}
+
+/// Creates two closed Polkadot Identity LOCs of `requester`, with IDs 0 and `loc_id`, so that voiding the latter falls
+/// back to the former in the index of closed identity LOCs.
+fn create_closed_identity_locs<T: pallet::Config>(origin: &RawOrigin<T::AccountId>, requester: &T::AccountId, loc_id: T::LocId) {
+	for id in [into_loc_id::<T>(0), loc_id] {
+		assert_ok!(LogionLoc::<T>::create_polkadot_identity_loc(origin.clone().into(), id, requester.clone()));
+		assert_ok!(LogionLoc::<T>::close(origin.clone().into(), id));
+	}
+}
+
+/// Adds links to newly created LOCs to given LOC.
+fn add_links<T: pallet::Config>(origin: &RawOrigin<T::AccountId>, loc_id: T::LocId, count: u32) {
+	let requester = funded_requester::<T>();
+	for i in 0..count {
+		let linked_loc_id = into_loc_id::<T>(1000 + i as u128);
+		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(origin.clone().into(), linked_loc_id, requester.clone()));
+		let link = LocLinkParams {
+			id: linked_loc_id,
+			nature: vec![1u8, 2u8, 3u8],
+		};
+		assert_ok!(LogionLoc::<T>::add_link(origin.clone().into(), loc_id, link));
+	}
+}
+
+fn collection_item_files<T: pallet::Config>(count: u32) -> Vec<CollectionItemFileParamsOf<T>> {
+	(0..count).map(|i| CollectionItemFileParams {
+		name: vec![1u8; T::MaxCollectionItemFileNameSize::get() as usize],
+		content_type: vec![1u8; T::MaxCollectionItemFileContentTypeSize::get() as usize],
+		size: 123456,
+		hash: into_hash::<T>(i + 1),
+	}).collect()
+}
+
+/// Returns terms and conditions elements referring to newly created and closed LOCs.
+fn terms_and_conditions<T: pallet::Config>(origin: &RawOrigin<T::AccountId>, count: u32) -> Vec<TermsAndConditionsElementParamsOf<T>> {
+	let requester = funded_requester::<T>();
+	(0..count).map(|i| {
+		let tc_loc_id = into_loc_id::<T>(2000 + i as u128);
+		assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(origin.clone().into(), tc_loc_id, requester.clone()));
+		assert_ok!(LogionLoc::<T>::close(origin.clone().into(), tc_loc_id));
+		TermsAndConditionsElementParams {
+			tc_type: vec![1u8; T::MaxTermsAndConditionsTypeSize::get() as usize],
+			tc_loc: tc_loc_id,
+			details: vec![1u8; T::MaxTermsAndConditionsDetailsSize::get() as usize],
+		}
+	}).collect()
+}
+
+/// Spreads `total` elements over `count` items, each item getting at most `max_per_item` elements.
+fn spread(total: u32, count: u32, max_per_item: u32) -> Vec<u32> {
+	(0..count).map(|i| (total / count + if i < total % count { 1 } else { 0 }).min(max_per_item)).collect()
 }
 
 fn into_loc_id<T: pallet::Config>(value: u128) -> <T as crate::Config>::LocId {
 	<T as crate::Config>::LocId::decode(&mut &value.encode()[..]).unwrap()
 }
 
+fn into_hash<T: pallet::Config>(value: u32) -> <T as crate::Config>::Hash {
+	let mut bytes = [0u8; 64];
+	bytes[..4].copy_from_slice(&value.to_le_bytes());
+	<T as crate::Config>::Hash::decode(&mut &bytes[..]).unwrap()
+}
+
+fn into_item_id<T: pallet::Config>(value: u8) -> <T as crate::Config>::CollectionItemId {
+	<T as crate::Config>::CollectionItemId::decode(&mut &[value; 32][..]).unwrap()
+}
+
 impl_benchmark_test_suite!(
 	LogionLoc,
 	crate::mock::new_test_ext(),
@@ -13,11 +13,17 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
-use frame_support::codec::{Decode, Encode};
+use frame_support::codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::dispatch::Vec;
+use frame_support::traits::{Currency, Get};
+use frame_support::{BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound};
 use scale_info::TypeInfo;
+use sp_std::fmt::Debug;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
-#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum LocType {
 	Transaction,
 	Identity,
@@ -31,31 +37,141 @@ impl Default for LocType {
 }
 
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct MetadataItem<AccountId> {
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase"))]
+pub struct MetadataItemParams<AccountId> {
 	name: Vec<u8>,
 	value: Vec<u8>,
 	submitter: AccountId,
 }
 
+pub type MetadataItemParamsOf<T> = MetadataItemParams<<T as frame_system::Config>::AccountId>;
+
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxNameSize, MaxValueSize))]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase", bound(serialize = "AccountId: Serialize", deserialize = "AccountId: Deserialize<'de>")))]
+pub struct MetadataItem<AccountId, MaxNameSize, MaxValueSize>
+where
+	AccountId: Clone + Eq + Debug,
+	MaxNameSize: Get<u32>,
+	MaxValueSize: Get<u32>,
+{
+	name: BoundedVec<u8, MaxNameSize>,
+	value: BoundedVec<u8, MaxValueSize>,
+	submitter: AccountId,
+	acknowledged: bool,
+}
+
+pub type MetadataItemOf<T> = MetadataItem<<T as frame_system::Config>::AccountId, <T as pallet::Config>::MaxMetadataItemNameSize, <T as pallet::Config>::MaxMetadataItemValueSize>;
+
+impl<AccountId, MaxNameSize, MaxValueSize> TryFrom<MetadataItemParams<AccountId>> for MetadataItem<AccountId, MaxNameSize, MaxValueSize>
+where
+	AccountId: Clone + Eq + Debug,
+	MaxNameSize: Get<u32>,
+	MaxValueSize: Get<u32>,
+{
+	type Error = ();
+
+	fn try_from(params: MetadataItemParams<AccountId>) -> Result<Self, Self::Error> {
+		Ok(MetadataItem {
+			name: params.name.try_into().map_err(|_| ())?,
+			value: params.value.try_into().map_err(|_| ())?,
+			submitter: params.submitter,
+			acknowledged: true,
+		})
+	}
+}
+
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct LocLink<LocId> {
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase"))]
+pub struct LocLinkParams<LocId> {
 	id: LocId,
 	nature: Vec<u8>,
 }
 
+pub type LocLinkParamsOf<T> = LocLinkParams<<T as pallet::Config>::LocId>;
+
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxNatureSize))]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase", bound(serialize = "LocId: Serialize", deserialize = "LocId: Deserialize<'de>")))]
+pub struct LocLink<LocId, MaxNatureSize>
+where
+	LocId: Clone + Eq + Debug,
+	MaxNatureSize: Get<u32>,
+{
+	id: LocId,
+	nature: BoundedVec<u8, MaxNatureSize>,
+}
+
+pub type LocLinkOf<T> = LocLink<<T as pallet::Config>::LocId, <T as pallet::Config>::MaxLinkNatureSize>;
+
+impl<LocId, MaxNatureSize> TryFrom<LocLinkParams<LocId>> for LocLink<LocId, MaxNatureSize>
+where
+	LocId: Clone + Eq + Debug,
+	MaxNatureSize: Get<u32>,
+{
+	type Error = ();
+
+	fn try_from(params: LocLinkParams<LocId>) -> Result<Self, Self::Error> {
+		Ok(LocLink {
+			id: params.id,
+			nature: params.nature.try_into().map_err(|_| ())?,
+		})
+	}
+}
+
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct File<Hash, AccountId> {
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase"))]
+pub struct FileParams<Hash, AccountId> {
 	hash: Hash,
 	nature: Vec<u8>,
 	submitter: AccountId,
 }
 
-#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub type FileParamsOf<T> = FileParams<<T as pallet::Config>::Hash, <T as frame_system::Config>::AccountId>;
+
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxNatureSize))]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase", bound(serialize = "Hash: Serialize, AccountId: Serialize", deserialize = "Hash: Deserialize<'de>, AccountId: Deserialize<'de>")))]
+pub struct File<Hash, AccountId, MaxNatureSize>
+where
+	Hash: Clone + Eq + Debug,
+	AccountId: Clone + Eq + Debug,
+	MaxNatureSize: Get<u32>,
+{
+	hash: Hash,
+	nature: BoundedVec<u8, MaxNatureSize>,
+	submitter: AccountId,
+	acknowledged: bool,
+}
+
+pub type FileOf<T> = File<<T as pallet::Config>::Hash, <T as frame_system::Config>::AccountId, <T as pallet::Config>::MaxFileNatureSize>;
+
+impl<Hash, AccountId, MaxNatureSize> TryFrom<FileParams<Hash, AccountId>> for File<Hash, AccountId, MaxNatureSize>
+where
+	Hash: Clone + Eq + Debug,
+	AccountId: Clone + Eq + Debug,
+	MaxNatureSize: Get<u32>,
+{
+	type Error = ();
+
+	fn try_from(params: FileParams<Hash, AccountId>) -> Result<Self, Self::Error> {
+		Ok(File {
+			hash: params.hash,
+			nature: params.nature.try_into().map_err(|_| ())?,
+			submitter: params.submitter,
+			acknowledged: true,
+		})
+	}
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase"))]
 pub struct LocVoidInfo<LocId> {
 	replacer: Option<LocId>,
 }
 
-#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum Requester<AccountId, LocId> {
 	None,
 	Account(AccountId),
@@ -73,15 +189,23 @@ impl<AccountId, LocId> Default for Requester<AccountId, LocId> {
 
 pub type CollectionSize = u32;
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub type CollectionItemRevision = u32;
+
+pub type BalanceOf<T> = <<T as pallet::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type NegativeImbalanceOf<T> = <<T as pallet::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+
+/// LOC header. Metadata, files and links are stored separately, only their number is kept here.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase"))]
 pub struct LegalOfficerCase<AccountId, Hash, LocId, BlockNumber> {
 	owner: AccountId,
 	requester: Requester<AccountId, LocId>,
-	metadata: Vec<MetadataItem<AccountId>>,
-	files: Vec<File<Hash, AccountId>>,
+	metadata_count: u32,
+	files_count: u32,
 	closed: bool,
 	loc_type: LocType,
-	links: Vec<LocLink<LocId>>,
+	links_count: u32,
 	void_info: Option<LocVoidInfo<LocId>>,
 	replacer_of: Option<LocId>,
 	collection_last_block_submission: Option<BlockNumber>,
@@ -90,42 +214,238 @@ pub struct LegalOfficerCase<AccountId, Hash, LocId, BlockNumber> {
 	seal: Option<Hash>,
 }
 
-pub type LegalOfficerCaseOf<T> = LegalOfficerCase<<T as frame_system::Config>::AccountId, <T as pallet::Config>::Hash, <T as pallet::Config>::LocId, <T as frame_system::Config>::BlockNumber>;
+pub type LegalOfficerCaseOf<T> = LegalOfficerCase<
+	<T as frame_system::Config>::AccountId,
+	<T as pallet::Config>::Hash,
+	<T as pallet::Config>::LocId,
+	<T as frame_system::Config>::BlockNumber,
+>;
+
+/// A LOC created at genesis. Its metadata items and files are acknowledged.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase"))]
+pub struct GenesisLoc<AccountId, Hash, LocId, BlockNumber> {
+	pub id: LocId,
+	pub owner: AccountId,
+	pub requester: Requester<AccountId, LocId>,
+	pub loc_type: LocType,
+	pub metadata: Vec<MetadataItemParams<AccountId>>,
+	pub files: Vec<FileParams<Hash, AccountId>>,
+	pub links: Vec<LocLinkParams<LocId>>,
+	pub closed: bool,
+	pub seal: Option<Hash>,
+	pub void_info: Option<LocVoidInfo<LocId>>,
+	pub collection_last_block_submission: Option<BlockNumber>,
+	pub collection_max_size: Option<CollectionSize>,
+	pub collection_can_upload: bool,
+}
+
+pub type GenesisLocOf<T> = GenesisLoc<
+	<T as frame_system::Config>::AccountId,
+	<T as pallet::Config>::Hash,
+	<T as pallet::Config>::LocId,
+	<T as frame_system::Config>::BlockNumber,
+>;
 
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct TermsAndConditionsElement<LocId> {
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase"))]
+pub struct TermsAndConditionsElementParams<LocId> {
 	tc_type: Vec<u8>,
 	tc_loc: LocId,
 	details: Vec<u8>,
 }
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct CollectionItem<Hash, LocId> {
-	description: Vec<u8>,
-	files: Vec<CollectionItemFile<Hash>>,
-	token: Option<CollectionItemToken>,
+pub type TermsAndConditionsElementParamsOf<T> = TermsAndConditionsElementParams<<T as pallet::Config>::LocId>;
+
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxTypeSize, MaxDetailsSize))]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase", bound(serialize = "LocId: Serialize", deserialize = "LocId: Deserialize<'de>")))]
+pub struct TermsAndConditionsElement<LocId, MaxTypeSize, MaxDetailsSize>
+where
+	LocId: Clone + Eq + Debug,
+	MaxTypeSize: Get<u32>,
+	MaxDetailsSize: Get<u32>,
+{
+	tc_type: BoundedVec<u8, MaxTypeSize>,
+	tc_loc: LocId,
+	details: BoundedVec<u8, MaxDetailsSize>,
+}
+
+pub type TermsAndConditionsElementOf<T> = TermsAndConditionsElement<<T as pallet::Config>::LocId, <T as pallet::Config>::MaxTermsAndConditionsTypeSize, <T as pallet::Config>::MaxTermsAndConditionsDetailsSize>;
+
+impl<LocId, MaxTypeSize, MaxDetailsSize> TryFrom<TermsAndConditionsElementParams<LocId>> for TermsAndConditionsElement<LocId, MaxTypeSize, MaxDetailsSize>
+where
+	LocId: Clone + Eq + Debug,
+	MaxTypeSize: Get<u32>,
+	MaxDetailsSize: Get<u32>,
+{
+	type Error = ();
+
+	fn try_from(params: TermsAndConditionsElementParams<LocId>) -> Result<Self, Self::Error> {
+		Ok(TermsAndConditionsElement {
+			tc_type: params.tc_type.try_into().map_err(|_| ())?,
+			tc_loc: params.tc_loc,
+			details: params.details.try_into().map_err(|_| ())?,
+		})
+	}
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase"))]
+pub struct CollectionItemVoidInfo<CollectionItemId> {
+	replacer: Option<CollectionItemId>,
+}
+
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxDescriptionSize, MaxFiles, MaxFileNameSize, MaxFileContentTypeSize, MaxTokenTypeSize, MaxTokenIdSize, MaxTermsAndConditions, MaxTermsAndConditionsTypeSize, MaxTermsAndConditionsDetailsSize))]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase", bound(serialize = "Hash: Serialize, LocId: Serialize, CollectionItemId: Serialize", deserialize = "Hash: Deserialize<'de>, LocId: Deserialize<'de>, CollectionItemId: Deserialize<'de>")))]
+pub struct CollectionItem<Hash, LocId, CollectionItemId, MaxDescriptionSize, MaxFiles, MaxFileNameSize, MaxFileContentTypeSize, MaxTokenTypeSize, MaxTokenIdSize, MaxTermsAndConditions, MaxTermsAndConditionsTypeSize, MaxTermsAndConditionsDetailsSize>
+where
+	Hash: Clone + Eq + Debug,
+	LocId: Clone + Eq + Debug,
+	CollectionItemId: Clone + Eq + Debug,
+	MaxDescriptionSize: Get<u32>,
+	MaxFiles: Get<u32>,
+	MaxFileNameSize: Get<u32>,
+	MaxFileContentTypeSize: Get<u32>,
+	MaxTokenTypeSize: Get<u32>,
+	MaxTokenIdSize: Get<u32>,
+	MaxTermsAndConditions: Get<u32>,
+	MaxTermsAndConditionsTypeSize: Get<u32>,
+	MaxTermsAndConditionsDetailsSize: Get<u32>,
+{
+	description: BoundedVec<u8, MaxDescriptionSize>,
+	files: BoundedVec<CollectionItemFile<Hash, MaxFileNameSize, MaxFileContentTypeSize>, MaxFiles>,
+	token: Option<CollectionItemToken<MaxTokenTypeSize, MaxTokenIdSize>>,
 	restricted_delivery: bool,
-	terms_and_conditions: Vec<TermsAndConditionsElement<LocId>>,
+	terms_and_conditions: BoundedVec<TermsAndConditionsElement<LocId, MaxTermsAndConditionsTypeSize, MaxTermsAndConditionsDetailsSize>, MaxTermsAndConditions>,
+	void_info: Option<CollectionItemVoidInfo<CollectionItemId>>,
+	replacer_of: Option<CollectionItemId>,
 }
 
-pub type CollectionItemOf<T> = CollectionItem<<T as pallet::Config>::Hash, <T as pallet::Config>::LocId>;
+pub type CollectionItemOf<T> = CollectionItem<
+	<T as pallet::Config>::Hash,
+	<T as pallet::Config>::LocId,
+	<T as pallet::Config>::CollectionItemId,
+	<T as pallet::Config>::MaxCollectionItemDescriptionSize,
+	<T as pallet::Config>::MaxCollectionItemFiles,
+	<T as pallet::Config>::MaxCollectionItemFileNameSize,
+	<T as pallet::Config>::MaxCollectionItemFileContentTypeSize,
+	<T as pallet::Config>::MaxCollectionItemTokenTypeSize,
+	<T as pallet::Config>::MaxCollectionItemTokenIdSize,
+	<T as pallet::Config>::MaxCollectionItemTermsAndConditions,
+	<T as pallet::Config>::MaxTermsAndConditionsTypeSize,
+	<T as pallet::Config>::MaxTermsAndConditionsDetailsSize,
+>;
 
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct CollectionItemFile<Hash> {
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase"))]
+pub struct CollectionItemFileParams<Hash> {
 	name: Vec<u8>,
 	content_type: Vec<u8>,
 	size: u32,
 	hash: Hash,
 }
 
-pub type CollectionItemFileOf<T> = CollectionItemFile<<T as pallet::Config>::Hash>;
+pub type CollectionItemFileParamsOf<T> = CollectionItemFileParams<<T as pallet::Config>::Hash>;
+
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxNameSize, MaxContentTypeSize))]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase", bound(serialize = "Hash: Serialize", deserialize = "Hash: Deserialize<'de>")))]
+pub struct CollectionItemFile<Hash, MaxNameSize, MaxContentTypeSize>
+where
+	Hash: Clone + Eq + Debug,
+	MaxNameSize: Get<u32>,
+	MaxContentTypeSize: Get<u32>,
+{
+	name: BoundedVec<u8, MaxNameSize>,
+	content_type: BoundedVec<u8, MaxContentTypeSize>,
+	size: u32,
+	hash: Hash,
+}
+
+pub type CollectionItemFileOf<T> = CollectionItemFile<<T as pallet::Config>::Hash, <T as pallet::Config>::MaxCollectionItemFileNameSize, <T as pallet::Config>::MaxCollectionItemFileContentTypeSize>;
+
+impl<Hash, MaxNameSize, MaxContentTypeSize> TryFrom<CollectionItemFileParams<Hash>> for CollectionItemFile<Hash, MaxNameSize, MaxContentTypeSize>
+where
+	Hash: Clone + Eq + Debug,
+	MaxNameSize: Get<u32>,
+	MaxContentTypeSize: Get<u32>,
+{
+	type Error = ();
+
+	fn try_from(params: CollectionItemFileParams<Hash>) -> Result<Self, Self::Error> {
+		Ok(CollectionItemFile {
+			name: params.name.try_into().map_err(|_| ())?,
+			content_type: params.content_type.try_into().map_err(|_| ())?,
+			size: params.size,
+			hash: params.hash,
+		})
+	}
+}
 
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct CollectionItemToken {
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase"))]
+pub struct CollectionItemTokenParams {
 	token_type: Vec<u8>,
 	token_id: Vec<u8>,
 }
 
+#[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxTypeSize, MaxIdSize))]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase", bound = ""))]
+pub struct CollectionItemToken<MaxTypeSize, MaxIdSize>
+where
+	MaxTypeSize: Get<u32>,
+	MaxIdSize: Get<u32>,
+{
+	token_type: BoundedVec<u8, MaxTypeSize>,
+	token_id: BoundedVec<u8, MaxIdSize>,
+}
+
+pub type CollectionItemTokenOf<T> = CollectionItemToken<<T as pallet::Config>::MaxCollectionItemTokenTypeSize, <T as pallet::Config>::MaxCollectionItemTokenIdSize>;
+
+impl<MaxTypeSize, MaxIdSize> TryFrom<CollectionItemTokenParams> for CollectionItemToken<MaxTypeSize, MaxIdSize>
+where
+	MaxTypeSize: Get<u32>,
+	MaxIdSize: Get<u32>,
+{
+	type Error = ();
+
+	fn try_from(params: CollectionItemTokenParams) -> Result<Self, Self::Error> {
+		Ok(CollectionItemToken {
+			token_type: params.token_type.try_into().map_err(|_| ())?,
+			token_id: params.token_id.try_into().map_err(|_| ())?,
+		})
+	}
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize), serde(rename_all = "camelCase"))]
+pub struct CollectionItemParams<CollectionItemId, Hash, LocId> {
+	id: CollectionItemId,
+	description: Vec<u8>,
+	files: Vec<CollectionItemFileParams<Hash>>,
+	token: Option<CollectionItemTokenParams>,
+	restricted_delivery: bool,
+	terms_and_conditions: Vec<TermsAndConditionsElementParams<LocId>>,
+}
+
+pub type CollectionItemParamsOf<T> = CollectionItemParams<<T as pallet::Config>::CollectionItemId, <T as pallet::Config>::Hash, <T as pallet::Config>::LocId>;
+
+/// Converts a list of call parameters into a bounded list of storage items, failing if the list
+/// or any of its elements exceeds its bounds.
+fn try_into_bounded<P, I, S>(params: Vec<P>) -> Result<BoundedVec<I, S>, ()>
+where
+	I: TryFrom<P, Error = ()>,
+	S: Get<u32>,
+{
+	let items: Vec<I> = params.into_iter()
+		.map(|param| I::try_from(param))
+		.collect::<Result<Vec<I>, ()>>()?;
+	items.try_into().map_err(|_| ())
+}
+
 pub mod weights;
 
 #[frame_support::pallet]
@@ -135,23 +455,30 @@ pub mod pallet {
 	use frame_support::{
 		dispatch::DispatchResultWithPostInfo,
 		pallet_prelude::*,
+		traits::{ExistenceRequirement, OnUnbalanced, ReservableCurrency, WithdrawReasons},
+		weights::Weight,
 	};
-	use codec::HasCompact;
+	use frame_support::sp_runtime::{Perbill, traits::{CheckedAdd, One, Saturating, Zero}};
+	use codec::{FullCodec, HasCompact};
 	use logion_shared::LocQuery;
+	use crate::migrations::multi_block::{MigrationBudget, MigrationCursor, MultiBlockMigration};
 	use super::*;
 	pub use crate::weights::WeightInfo;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// LOC identifier
-		type LocId: Member + Parameter + Default + Copy + HasCompact;
+		type LocId: Member + Parameter + Default + Copy + HasCompact + MaxEncodedLen + MaybeSerializeDeserialize + One + CheckedAdd + PartialOrd;
 
 		/// Type for hashes stored in LOCs
-		type Hash: Member + Parameter + Default + Copy + Ord;
+		type Hash: Member + Parameter + Default + Copy + Ord + MaxEncodedLen + MaybeSerializeDeserialize;
 
 		/// The origin (must be signed) which can create a LOC.
 		type CreateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The origin which can transfer the ownership of any LOC.
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -159,33 +486,108 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Collection item identifier
-		type CollectionItemId: Member + Parameter + Default + Copy;
+		type CollectionItemId: Member + Parameter + Default + Copy + MaxEncodedLen + MaybeSerializeDeserialize;
 
 		/// The maximum size of a LOC metadata name
-		type MaxMetadataItemNameSize: Get<usize>;
+		type MaxMetadataItemNameSize: Get<u32>;
 
 		/// The maximum size of a LOC metadata value
-		type MaxMetadataItemValueSize: Get<usize>;
+		type MaxMetadataItemValueSize: Get<u32>;
 
 		/// The maximum size of a LOC file nature
-		type MaxFileNatureSize: Get<usize>;
+		type MaxFileNatureSize: Get<u32>;
 
 		/// The maximum size of a LOC link nature
-		type MaxLinkNatureSize: Get<usize>;
+		type MaxLinkNatureSize: Get<u32>;
+
+		/// The maximum number of metadata items in a LOC
+		type MaxLocMetadata: Get<u32>;
+
+		/// The maximum number of files in a LOC
+		type MaxLocFiles: Get<u32>;
+
+		/// The maximum number of links in a LOC
+		type MaxLocLinks: Get<u32>;
 
 		/// The maximum size of a Collection Item description
-		type MaxCollectionItemDescriptionSize: Get<usize>;
+		type MaxCollectionItemDescriptionSize: Get<u32>;
 
 		/// The maximum size of a Collection Item Token Type
-		type MaxCollectionItemTokenTypeSize: Get<usize>;
+		type MaxCollectionItemTokenTypeSize: Get<u32>;
 
 		/// The maximum size of a Collection Item Token ID
-		type MaxCollectionItemTokenIdSize: Get<usize>;
+		type MaxCollectionItemTokenIdSize: Get<u32>;
+
+		/// The maximum number of files attached to a Collection Item
+		type MaxCollectionItemFiles: Get<u32>;
+
+		/// The maximum size of a Collection Item file name
+		type MaxCollectionItemFileNameSize: Get<u32>;
+
+		/// The maximum size of a Collection Item file content type
+		type MaxCollectionItemFileContentTypeSize: Get<u32>;
+
+		/// The maximum number of terms and conditions elements of a Collection Item
+		type MaxCollectionItemTermsAndConditions: Get<u32>;
+
+		/// The maximum size of a terms and conditions element type
+		type MaxTermsAndConditionsTypeSize: Get<u32>;
+
+		/// The maximum size of a terms and conditions element details
+		type MaxTermsAndConditionsDetailsSize: Get<u32>;
+
+		/// The maximum number of items added to a collection by a single `add_collection_items` call
+		#[pallet::constant]
+		type MaxItemsPerBatch: Get<u32>;
+
+		/// The currency used to reserve storage deposits
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit reserved for each new storage entry (LOC, LOC item or collection item)
+		#[pallet::constant]
+		type DepositBase: Get<BalanceOf<Self>>;
+
+		/// The deposit reserved for each byte of a new storage entry
+		#[pallet::constant]
+		type DepositPerByte: Get<BalanceOf<Self>>;
+
+		/// The fee due by the requester of a Transaction LOC with a Polkadot requester, see `pay_loc_fee`
+		#[pallet::constant]
+		type TransactionLocFee: Get<BalanceOf<Self>>;
+
+		/// The fee due by the requester of an Identity LOC with a Polkadot requester, see `pay_loc_fee`
+		#[pallet::constant]
+		type IdentityLocFee: Get<BalanceOf<Self>>;
+
+		/// The fee due by the requester of a Collection LOC with a Polkadot requester, see `pay_loc_fee`
+		#[pallet::constant]
+		type CollectionLocFee: Get<BalanceOf<Self>>;
+
+		/// The fee paid by the requester to the legal officer for each item they add to a collection
+		#[pallet::constant]
+		type CollectionItemFee: Get<BalanceOf<Self>>;
+
+		/// The share of each fee which goes to the treasury instead of the legal officer
+		#[pallet::constant]
+		type TreasuryShare: Get<Perbill>;
+
+		/// Handler for the treasury share of the fees
+		type FeeDestination: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The migration run by `on_idle` once started by `migrations::multi_block::StartMultiBlockMigration`, `()` if none
+		type MultiBlockMigration: MultiBlockMigration;
 	}
 
+	/// The current storage version. Versions up to 18 were previously recorded in the legacy
+	/// `PalletStorageVersion` value, see `migrations::storage_version`.
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(18);
+
+	/// The maximum number of dependent LOCs reported when a LOC is made void.
+	pub const MAX_VOID_DEPENDENTS: u32 = 16;
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// All LOCs indexed by ID.
@@ -193,16 +595,74 @@ pub mod pallet {
 	#[pallet::getter(fn loc)]
 	pub type LocMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, LegalOfficerCaseOf<T>>;
 
-	/// Requested LOCs by account ID.
+	/// LOC metadata items by LOC ID and index, indexes ranging from 0 to the LOC's `metadata_count` excluded.
+	#[pallet::storage]
+	#[pallet::getter(fn loc_metadata)]
+	pub type LocMetadataMap<T> = StorageDoubleMap<_, Blake2_128Concat, <T as Config>::LocId, Twox64Concat, u32, MetadataItemOf<T>>;
+
+	/// LOC files by LOC ID and index, indexes ranging from 0 to the LOC's `files_count` excluded.
+	#[pallet::storage]
+	#[pallet::getter(fn loc_files)]
+	pub type LocFilesMap<T> = StorageDoubleMap<_, Blake2_128Concat, <T as Config>::LocId, Twox64Concat, u32, FileOf<T>>;
+
+	/// LOC links by LOC ID and index, indexes ranging from 0 to the LOC's `links_count` excluded.
+	#[pallet::storage]
+	#[pallet::getter(fn loc_links)]
+	pub type LocLinksMap<T> = StorageDoubleMap<_, Blake2_128Concat, <T as Config>::LocId, Twox64Concat, u32, LocLinkOf<T>>;
+
+	/// Legacy list of requested LOCs by account ID, moved to AccountLocs by `migrations::v18::MigrateToV18`.
 	#[pallet::storage]
-	#[pallet::getter(fn account_locs)]
+	#[pallet::unbounded]
 	pub type AccountLocsMap<T> = StorageMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, Vec<<T as Config>::LocId>>;
 
-	/// Requested LOCs by logion Identity LOC.
+	/// Legacy list of requested LOCs by logion Identity LOC, moved to IdentityLocLocs by `migrations::v18::MigrateToV18`.
 	#[pallet::storage]
-	#[pallet::getter(fn identity_loc_locs)]
+	#[pallet::unbounded]
 	pub type IdentityLocLocsMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, Vec<<T as Config>::LocId>>;
 
+	/// The ID which will be tried first by the next LOC creation with an allocated ID.
+	#[pallet::storage]
+	#[pallet::getter(fn next_loc_id)]
+	pub type NextLocId<T> = StorageValue<_, <T as Config>::LocId, ValueQuery>;
+
+	/// Requested LOCs by account ID.
+	#[pallet::storage]
+	pub type AccountLocs<T> = StorageDoubleMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, Blake2_128Concat, <T as Config>::LocId, ()>;
+
+	/// Number of entries in AccountLocs by account ID.
+	#[pallet::storage]
+	pub type AccountLocsCount<T> = StorageMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, u32, ValueQuery>;
+
+	/// Requested LOCs by logion Identity LOC.
+	#[pallet::storage]
+	pub type IdentityLocLocs<T> = StorageDoubleMap<_, Blake2_128Concat, <T as Config>::LocId, Blake2_128Concat, <T as Config>::LocId, ()>;
+
+	/// Number of entries in IdentityLocLocs by logion Identity LOC.
+	#[pallet::storage]
+	pub type IdentityLocLocsCount<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, u32, ValueQuery>;
+
+	/// LOCs by owner.
+	#[pallet::storage]
+	pub type LocsByOwner<T> = StorageDoubleMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, Blake2_128Concat, <T as Config>::LocId, ()>;
+
+	/// Closed and non-void Polkadot Identity LOCs by requester account and legal officer.
+	#[pallet::storage]
+	#[pallet::getter(fn closed_identity_locs)]
+	pub type ClosedIdentityLocs<T> = StorageDoubleMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, Blake2_128Concat, <T as frame_system::Config>::AccountId, <T as Config>::LocId>;
+
+	/// All closed and non-void Polkadot Identity LOCs (second key) by requester account and legal officer (first key).
+	#[pallet::storage]
+	pub type AllClosedIdentityLocs<T> = StorageDoubleMap<_, Blake2_128Concat, (<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId), Blake2_128Concat, <T as Config>::LocId, ()>;
+
+	/// LOCs and collection items (second key) by file hash (first key).
+	#[pallet::storage]
+	pub type FileHashIndex<T> = StorageDoubleMap<_, Blake2_128Concat, <T as Config>::Hash, Blake2_128Concat, (<T as Config>::LocId, Option<<T as Config>::CollectionItemId>), ()>;
+
+	/// Number of references (links or terms and conditions) from a LOC (second key) to another LOC (first key).
+	#[pallet::storage]
+	#[pallet::getter(fn linked_from)]
+	pub type LinkedFrom<T> = StorageDoubleMap<_, Blake2_128Concat, <T as Config>::LocId, Blake2_128Concat, <T as Config>::LocId, u32, ValueQuery>;
+
 	/// Collection items by LOC ID.
 	#[pallet::storage]
 	#[pallet::getter(fn collection_items)]
@@ -213,17 +673,197 @@ pub mod pallet {
 	#[pallet::getter(fn collection_size)]
 	pub type CollectionSizeMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, CollectionSize>;
 
+	/// Previous versions of collection items by LOC ID, item ID and revision.
+	#[pallet::storage]
+	#[pallet::getter(fn collection_item_revisions)]
+	pub type CollectionItemRevisionsMap<T> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, <T as Config>::LocId>,
+			NMapKey<Blake2_128Concat, <T as Config>::CollectionItemId>,
+			NMapKey<Twox64Concat, CollectionItemRevision>,
+		),
+		CollectionItemOf<T>,
+	>;
+
+	/// Current revision of collection items by LOC ID and item ID.
+	#[pallet::storage]
+	#[pallet::getter(fn collection_item_revision)]
+	pub type CollectionItemRevisionMap<T> = StorageDoubleMap<_, Blake2_128Concat, <T as Config>::LocId, Blake2_128Concat, <T as Config>::CollectionItemId, CollectionItemRevision, ValueQuery>;
+
+	/// Storage deposits by LOC ID and depositor.
+	#[pallet::storage]
+	#[pallet::getter(fn deposits)]
+	pub type DepositsMap<T> = StorageDoubleMap<_, Blake2_128Concat, <T as Config>::LocId, Blake2_128Concat, <T as frame_system::Config>::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// LOC fees not yet paid by the requester, by LOC ID.
+	#[pallet::storage]
+	#[pallet::getter(fn loc_fees)]
+	pub type LocFees<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, BalanceOf<T>>;
+
+	/// Progress of the ongoing multi-block migration, if any. Mutating calls are rejected while it is set.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	#[pallet::getter(fn ongoing_migration)]
+	pub type OngoingMigration<T> = StorageValue<_, MigrationCursor>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// LOCs to create, no deposit is reserved and no fee is charged
+		pub locs: Vec<GenesisLocOf<T>>,
+		/// Items to add to collection LOCs, by collection LOC ID
+		pub collection_items: Vec<(T::LocId, CollectionItemParamsOf<T>)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self {
+				locs: Vec::new(),
+				collection_items: Vec::new(),
+			}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			// Genesis storage is at the current layout, calls are rejected otherwise
+			STORAGE_VERSION.put::<Pallet<T>>();
+			for loc in self.locs.iter() {
+				assert!(!<LocMap<T>>::contains_key(&loc.id), "Duplicate genesis LOC");
+				assert!(loc.metadata.len() <= T::MaxLocMetadata::get() as usize
+					&& loc.files.len() <= T::MaxLocFiles::get() as usize
+					&& loc.links.len() <= T::MaxLocLinks::get() as usize, "Too many genesis LOC items");
+				if loc.loc_type == LocType::Collection {
+					assert!(matches!(loc.requester, RequesterOf::<T>::Account(_)), "Genesis collection LOC requester must be an account");
+					Pallet::<T>::validate_collection_limits(&loc.collection_last_block_submission, &loc.collection_max_size)
+						.expect("Invalid genesis collection LOC");
+				}
+
+				let legal_officer_case = LegalOfficerCaseOf::<T> {
+					owner: loc.owner.clone(),
+					requester: loc.requester.clone(),
+					metadata_count: loc.metadata.len() as u32,
+					files_count: loc.files.len() as u32,
+					closed: loc.closed,
+					loc_type: loc.loc_type.clone(),
+					links_count: loc.links.len() as u32,
+					void_info: loc.void_info.clone(),
+					replacer_of: None,
+					collection_last_block_submission: loc.collection_last_block_submission,
+					collection_max_size: loc.collection_max_size,
+					collection_can_upload: loc.collection_can_upload,
+					seal: loc.seal,
+				};
+				for (index, item) in loc.metadata.iter().enumerate() {
+					let item: MetadataItemOf<T> = item.clone().try_into().expect("Invalid genesis metadata item");
+					Pallet::<T>::validate_submitter(&item.submitter, &legal_officer_case).expect("Invalid genesis metadata item submitter");
+					<LocMetadataMap<T>>::insert(&loc.id, index as u32, item);
+				}
+				for (index, file) in loc.files.iter().enumerate() {
+					let file: FileOf<T> = file.clone().try_into().expect("Invalid genesis file");
+					Pallet::<T>::validate_submitter(&file.submitter, &legal_officer_case).expect("Invalid genesis file submitter");
+					Pallet::<T>::index_file_hash(&file.hash, &loc.id, None);
+					<LocFilesMap<T>>::insert(&loc.id, index as u32, file);
+				}
+				for (index, link) in loc.links.iter().enumerate() {
+					let link: LocLinkOf<T> = link.clone().try_into().expect("Invalid genesis link");
+					Pallet::<T>::add_reference(&link.id, &loc.id);
+					<LocLinksMap<T>>::insert(&loc.id, index as u32, link);
+				}
+
+				<LocsByOwner<T>>::insert(&loc.owner, &loc.id, ());
+				match &loc.requester {
+					RequesterOf::<T>::Account(requester) => Pallet::<T>::link_with_account(requester, &loc.id),
+					RequesterOf::<T>::Loc(requester_loc_id) => Pallet::<T>::link_with_identity_loc(requester_loc_id, &loc.id),
+					RequesterOf::<T>::None => (),
+				}
+				if legal_officer_case.closed && legal_officer_case.void_info.is_none() {
+					Pallet::<T>::index_closed_identity_loc(&loc.id, &legal_officer_case);
+				}
+				<LocMap<T>>::insert(&loc.id, legal_officer_case);
+				Pallet::<T>::advance_next_loc_id(&loc.id);
+			}
+
+			for loc in self.locs.iter() {
+				if let RequesterOf::<T>::Loc(requester_loc_id) = &loc.requester {
+					let requester_loc = <LocMap<T>>::get(requester_loc_id).expect("Genesis requester LOC not found");
+					assert!(!Pallet::<T>::is_valid_logion_id(&requester_loc), "Genesis requester LOC must be a valid logion identity LOC");
+				}
+				for link in loc.links.iter() {
+					assert!(<LocMap<T>>::contains_key(&link.id), "Genesis linked LOC not found");
+				}
+				if let Some(LocVoidInfo { replacer: Some(replacer_loc_id) }) = &loc.void_info {
+					let legal_officer_case = <LocMap<T>>::get(&loc.id).expect("genesis LOC was inserted above; qed");
+					Pallet::<T>::validate_replacer(&legal_officer_case, replacer_loc_id).expect("Invalid genesis replacer LOC");
+					<LocMap<T>>::mutate(replacer_loc_id, |replacer_loc| {
+						let mutable_replacer_loc = replacer_loc.as_mut().unwrap();
+						mutable_replacer_loc.replacer_of = Some(loc.id);
+					});
+				}
+			}
+
+			for (collection_loc_id, params) in self.collection_items.iter() {
+				let collection_loc = <LocMap<T>>::get(collection_loc_id).expect("Genesis collection LOC not found");
+				assert!(collection_loc.loc_type == LocType::Collection, "Genesis collection items must belong to a collection LOC");
+				assert!(!<CollectionItemsMap<T>>::contains_key(collection_loc_id, &params.id), "Duplicate genesis collection item");
+
+				let params = params.clone();
+				let item = Pallet::<T>::build_collection_item(params.description, params.files, params.token, params.restricted_delivery, params.terms_and_conditions)
+					.expect("Invalid genesis collection item");
+				assert!(!Pallet::<T>::collection_limits_reached(collection_loc_id, &collection_loc, 1), "Genesis collection limits reached");
+				Pallet::<T>::validate_collection_item(&collection_loc, &item).expect("Invalid genesis collection item");
+				Pallet::<T>::index_collection_item_files(collection_loc_id, &params.id, &item);
+				Pallet::<T>::add_terms_and_conditions_references(collection_loc_id, &item);
+				<CollectionItemsMap<T>>::insert(collection_loc_id, params.id, item);
+				let collection_size = <CollectionSizeMap<T>>::get(collection_loc_id).unwrap_or(0);
+				<CollectionSizeMap<T>>::insert(collection_loc_id, collection_size + 1);
+			}
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// Issued upon LOC creation. [locId]
-		LocCreated(T::LocId),
+		/// Issued upon LOC creation. [locId, owner, requester, locType]
+		LocCreated(T::LocId, T::AccountId, RequesterOf<T>, LocType),
 		/// Issued when LOC is closed. [locId]
 		LocClosed(T::LocId),
 		/// Issued when LOC is voided. [locId]
 		LocVoid(T::LocId),
 		/// Issued when an item was added to a collection. [locId, collectionItemId]
 		ItemAdded(T::LocId, T::CollectionItemId),
+		/// Issued when a metadata item was added to a LOC. [locId, name, submitter]
+		MetadataAdded(T::LocId, Vec<u8>, T::AccountId),
+		/// Issued when a file was added to a LOC. [locId, hash, submitter]
+		FileAdded(T::LocId, <T as Config>::Hash, T::AccountId),
+		/// Issued when a link was added to a LOC. [locId, target, submitter]
+		LinkAdded(T::LocId, T::LocId, T::AccountId),
+		/// Issued when a LOC was closed and sealed. [locId, seal]
+		LocSealed(T::LocId, <T as Config>::Hash),
+		/// Issued when an item of a collection was updated. [locId, collectionItemId, revision]
+		ItemUpdated(T::LocId, T::CollectionItemId, CollectionItemRevision),
+		/// Issued when an item of a collection was voided. [locId, collectionItemId]
+		ItemVoid(T::LocId, T::CollectionItemId),
+		/// Issued when fees were charged to the signer of a call. [locId, payer, legalOfficerFee, treasuryFee]
+		FeesCharged(T::LocId, T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// Issued when a metadata item was deleted from a LOC. [locId, name]
+		MetadataDeleted(T::LocId, Vec<u8>),
+		/// Issued when a file was deleted from a LOC. [locId, hash]
+		FileDeleted(T::LocId, <T as Config>::Hash),
+		/// Issued when a link was deleted from a LOC. [locId, target]
+		LinkDeleted(T::LocId, T::LocId),
+		/// Issued when a metadata item submitted by the requester was acknowledged. [locId, name]
+		MetadataAcknowledged(T::LocId, Vec<u8>),
+		/// Issued when a file submitted by the requester was acknowledged. [locId, hash]
+		FileAcknowledged(T::LocId, <T as Config>::Hash),
+		/// Issued when the ownership of a LOC was transferred. [locId, previousOwner, newOwner]
+		OwnershipTransferred(T::LocId, T::AccountId, T::AccountId),
+		/// Issued when (some of) the LOCs of a legal officer were transferred. [previousOwner, newOwner, transferredLocs]
+		OwnershipBulkTransferred(T::AccountId, T::AccountId, u32),
+		/// Issued when a voided LOC is still referenced by other LOCs, at most `MAX_VOID_DEPENDENTS` of them are reported. [locId, dependentLocIds]
+		VoidLocHasDependents(T::LocId, Vec<T::LocId>),
 	}
 
 	#[pallet::error]
@@ -288,35 +928,55 @@ pub mod pallet {
 		TermsAndConditionsLocNotClosed,
 		/// TermsAndConditions LOC is void
 		TermsAndConditionsLocVoid,
+		/// The LOC already has the maximum number of metadata items
+		TooManyMetadataItems,
+		/// The LOC already has the maximum number of files
+		TooManyFiles,
+		/// The LOC already has the maximum number of links
+		TooManyLinks,
+		/// The payer cannot afford the storage deposit or the fees
+		InsufficientFunds,
+		/// Target metadata item does not exist
+		MetadataItemNotFound,
+		/// Target file does not exist
+		FileNotFound,
+		/// Target link does not exist
+		LinkNotFound,
+		/// Occurs when trying to acknowledge an item already acknowledged
+		ItemAlreadyAcknowledged,
+		/// Occurs when trying to close a LOC with items not yet acknowledged
+		CannotCloseUnacknowledged,
+		/// Occurs when trying to update or void a collection item which does not exist
+		CollectionItemNotFound,
+		/// Occurs when trying to replace void item with a non-existent item
+		ReplacerItemNotFound,
+		/// Occurs when trying to void an item by replacing it with an already void item
+		ReplacerItemAlreadyVoid,
+		/// Occurs when trying to void an item by replacing it with an item already replacing another item
+		ReplacerItemAlreadyReplacing,
+		/// No LOC ID is left to allocate
+		LocIdOverflow,
+		/// A multi-block migration is in progress or storage was not migrated to the current version, state cannot be
+		/// mutated until migration completes
+		MigrationInProgress,
+		/// The new owner of a LOC must be a legal officer
+		NewOwnerNotLegalOfficer,
+		/// Occurs when trying to pay the fee of a LOC which has no fee due
+		NoLocFeeDue,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
-
-	#[derive(Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
-	pub enum StorageVersion {
-		V1,
-		V2MakeLocVoid,
-		V3RequesterEnum,
-		V4ItemSubmitter,
-		V5Collection,
-		V6ItemUpload,
-		V7ItemToken,
-		V8AddSeal,
-		V9TermsAndConditions,
-	}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 
-	impl Default for StorageVersion {
-		fn default() -> StorageVersion {
-			return StorageVersion::V8AddSeal;
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			if let Some(cursor) = <OngoingMigration<T>>::get() {
+				T::DbWeight::get().reads(1).saturating_add(Self::run_multi_block_migration(cursor, remaining_weight))
+			} else {
+				T::DbWeight::get().reads(1)
+			}
 		}
 	}
 
-	/// Storage version
-	#[pallet::storage]
-	#[pallet::getter(fn pallet_storage_version)]
-	pub type PalletStorageVersion<T> = StorageValue<_, StorageVersion, ValueQuery>;
-
 	#[pallet::call]
 	impl<T:Config> Pallet<T> {
 
@@ -327,21 +987,10 @@ pub mod pallet {
 			#[pallet::compact] loc_id: T::LocId,
 			requester_account_id: T::AccountId,
 		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
 			T::CreateOrigin::ensure_origin(origin.clone())?;
 			let who = ensure_signed(origin)?;
-
-			if <LocMap<T>>::contains_key(&loc_id) {
-				Err(Error::<T>::AlreadyExists)?
-			} else {
-				let requester = RequesterOf::<T>::Account(requester_account_id.clone());
-				let loc = Self::build_open_loc(&who, &requester, LocType::Identity);
-
-				<LocMap<T>>::insert(loc_id, loc);
-				Self::link_with_account(&requester_account_id, &loc_id);
-
-				Self::deposit_event(Event::LocCreated(loc_id));
-				Ok(().into())
-			}
+			Self::do_create_polkadot_identity_loc(who, loc_id, requester_account_id)
 		}
 
 		/// Creates a new logion Identity LOC i.e. a LOC describing a real identity not yet linked to an AccountId
@@ -350,19 +999,10 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			#[pallet::compact] loc_id: T::LocId,
 		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
 			T::CreateOrigin::ensure_origin(origin.clone())?;
 			let who = ensure_signed(origin)?;
-
-			if <LocMap<T>>::contains_key(&loc_id) {
-				Err(Error::<T>::AlreadyExists)?
-			} else {
-				let requester = RequesterOf::<T>::None;
-				let loc = Self::build_open_loc(&who, &requester, LocType::Identity);
-				<LocMap<T>>::insert(loc_id, loc);
-
-				Self::deposit_event(Event::LocCreated(loc_id));
-				Ok(().into())
-			}
+			Self::do_create_logion_identity_loc(who, loc_id)
 		}
 
 		/// Creates a new Polkadot Transaction LOC i.e. a LOC requested with an AccountId
@@ -372,21 +1012,10 @@ pub mod pallet {
 			#[pallet::compact] loc_id: T::LocId,
 			requester_account_id: T::AccountId,
 		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
 			T::CreateOrigin::ensure_origin(origin.clone())?;
 			let who = ensure_signed(origin)?;
-
-			if <LocMap<T>>::contains_key(&loc_id) {
-				Err(Error::<T>::AlreadyExists)?
-			} else {
-				let requester = RequesterOf::<T>::Account(requester_account_id.clone());
-				let loc = Self::build_open_loc(&who, &requester, LocType::Transaction);
-
-				<LocMap<T>>::insert(loc_id, loc);
-				Self::link_with_account(&requester_account_id, &loc_id);
-
-				Self::deposit_event(Event::LocCreated(loc_id));
-				Ok(().into())
-			}
+			Self::do_create_polkadot_transaction_loc(who, loc_id, requester_account_id)
 		}
 
 		/// Creates a new logion Transaction LOC i.e. a LOC requested with a logion Identity LOC
@@ -396,29 +1025,10 @@ pub mod pallet {
 			#[pallet::compact] loc_id: T::LocId,
 			requester_loc_id: T::LocId,
 		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
 			T::CreateOrigin::ensure_origin(origin.clone())?;
 			let who = ensure_signed(origin)?;
-
-			if <LocMap<T>>::contains_key(&loc_id) {
-				Err(Error::<T>::AlreadyExists)?
-			} else {
-				let requester_loc = <LocMap<T>>::get(&requester_loc_id);
-				match requester_loc {
-					None => Err(Error::<T>::UnexpectedRequester)?,
-					Some(loc) =>
-						if Self::is_valid_logion_id(&loc) {
-							Err(Error::<T>::UnexpectedRequester)?
-						} else {
-							let requester = RequesterOf::<T>::Loc(requester_loc_id.clone());
-							let new_loc = Self::build_open_loc(&who, &requester, LocType::Transaction);
-							<LocMap<T>>::insert(loc_id, new_loc);
-							Self::link_with_identity_loc(&requester_loc_id, &loc_id);
-						},
-				}
-
-				Self::deposit_event(Event::LocCreated(loc_id));
-				Ok(().into())
-			}
+			Self::do_create_logion_transaction_loc(who, loc_id, requester_loc_id)
 		}
 
 		/// Creates a new Collection LOC
@@ -431,54 +1041,29 @@ pub mod pallet {
 			collection_max_size: Option<u32>,
 			collection_can_upload: bool,
 		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
 			T::CreateOrigin::ensure_origin(origin.clone())?;
 			let who = ensure_signed(origin)?;
-
-			if collection_last_block_submission.is_none() && collection_max_size.is_none() {
-				Err(Error::<T>::CollectionHasNoLimit)?
-			}
-
-			if <LocMap<T>>::contains_key(&loc_id) {
-				Err(Error::<T>::AlreadyExists)?
-			} else {
-				let requester = RequesterOf::<T>::Account(requester_account_id.clone());
-				let loc = Self::build_open_collection_loc(
-					&who,
-					&requester,
-					collection_last_block_submission,
-					collection_max_size,
-					collection_can_upload,
-				);
-
-				<LocMap<T>>::insert(loc_id, loc);
-				Self::link_with_account(&requester_account_id, &loc_id);
-
-				Self::deposit_event(Event::LocCreated(loc_id));
-				Ok(().into())
-			}
+			Self::do_create_collection_loc(who, loc_id, requester_account_id, collection_last_block_submission, collection_max_size, collection_can_upload)
 		}
 
-		/// Add LOC metadata
-		#[pallet::weight(T::WeightInfo::add_metadata())]
+		/// Add LOC metadata. Metadata submitted by the requester must be acknowledged by the owner.
+		#[pallet::weight(T::WeightInfo::add_metadata(item.value.len() as u32))]
 		pub fn add_metadata(
 			origin: OriginFor<T>,
 			#[pallet::compact] loc_id: T::LocId,
-			item: MetadataItem<T::AccountId>
+			item: MetadataItemParamsOf<T>
 		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
 			let who = ensure_signed(origin)?;
 
-			if item.name.len() > T::MaxMetadataItemNameSize::get() {
-				Err(Error::<T>::MetadataItemInvalid)?
-			}
-			if item.value.len() > T::MaxMetadataItemValueSize::get() {
-				Err(Error::<T>::MetadataItemInvalid)?
-			}
+			let mut item: MetadataItemOf<T> = item.try_into().map_err(|_| Error::<T>::MetadataItemInvalid)?;
 
 			if !<LocMap<T>>::contains_key(&loc_id) {
 				Err(Error::<T>::NotFound)?
 			} else {
 				let loc = <LocMap<T>>::get(&loc_id).unwrap();
-				if loc.owner != who {
+				if loc.owner != who && !(Self::is_requester(&who, &loc) && item.submitter == who) {
 					Err(Error::<T>::Unauthorized)?
 				} else if loc.closed {
 					Err(Error::<T>::CannotMutate)?
@@ -486,33 +1071,43 @@ pub mod pallet {
 					Err(Error::<T>::CannotMutateVoid)?
 				} else {
 					Self::validate_submitter(&item.submitter, &loc)?;
+					if loc.metadata_count >= T::MaxLocMetadata::get() {
+						Err(Error::<T>::TooManyMetadataItems)?
+					}
+					item.acknowledged = loc.owner == who;
+					let deposit_size = item.encoded_size();
+					let name = item.name.to_vec();
+					let submitter = item.submitter.clone();
+					<LocMetadataMap<T>>::insert(&loc_id, loc.metadata_count, item);
 					<LocMap<T>>::mutate(loc_id, |loc| {
 						let mutable_loc = loc.as_mut().unwrap();
-						mutable_loc.metadata.push(item);
+						mutable_loc.metadata_count += 1;
 					});
+					Self::reserve_deposit(&who, &loc_id, deposit_size)?;
+
+					Self::deposit_event(Event::MetadataAdded(loc_id, name, submitter));
 					Ok(().into())
 				}
 			}
 		}
 
-		/// Add file to LOC
+		/// Add file to LOC. Files submitted by the requester must be acknowledged by the owner.
 		#[pallet::weight(T::WeightInfo::add_file())]
 		pub fn add_file(
 			origin: OriginFor<T>,
 			#[pallet::compact] loc_id: T::LocId,
-			file: File<<T as pallet::Config>::Hash, T::AccountId>
+			file: FileParamsOf<T>
 		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
 			let who = ensure_signed(origin)?;
 
-			if file.nature.len() > T::MaxFileNatureSize::get() {
-				Err(Error::<T>::FileInvalid)?
-			}
+			let mut file: FileOf<T> = file.try_into().map_err(|_| Error::<T>::FileInvalid)?;
 
 			if !<LocMap<T>>::contains_key(&loc_id) {
 				Err(Error::<T>::NotFound)?
 			} else {
 				let loc = <LocMap<T>>::get(&loc_id).unwrap();
-				if loc.owner != who {
+				if loc.owner != who && !(Self::is_requester(&who, &loc) && file.submitter == who) {
 					Err(Error::<T>::Unauthorized)?
 				} else if loc.closed {
 					Err(Error::<T>::CannotMutate)?
@@ -520,10 +1115,22 @@ pub mod pallet {
 					Err(Error::<T>::CannotMutateVoid)?
 				} else {
 					Self::validate_submitter(&file.submitter, &loc)?;
+					if loc.files_count >= T::MaxLocFiles::get() {
+						Err(Error::<T>::TooManyFiles)?
+					}
+					file.acknowledged = loc.owner == who;
+					let deposit_size = file.encoded_size();
+					let hash = file.hash;
+					let submitter = file.submitter.clone();
+					<LocFilesMap<T>>::insert(&loc_id, loc.files_count, file);
 					<LocMap<T>>::mutate(loc_id, |loc| {
 						let mutable_loc = loc.as_mut().unwrap();
-						mutable_loc.files.push(file);
+						mutable_loc.files_count += 1;
 					});
+					Self::reserve_deposit(&who, &loc_id, deposit_size)?;
+					Self::index_file_hash(&hash, &loc_id, None);
+
+					Self::deposit_event(Event::FileAdded(loc_id, hash, submitter));
 					Ok(().into())
 				}
 			}
@@ -534,13 +1141,12 @@ pub mod pallet {
 		pub fn add_link(
 			origin: OriginFor<T>,
 			#[pallet::compact] loc_id: T::LocId,
-			link: LocLink<T::LocId>
+			link: LocLinkParamsOf<T>
 		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
 			let who = ensure_signed(origin)?;
 
-			if link.nature.len() > T::MaxLinkNatureSize::get() {
-				Err(Error::<T>::LocLinkInvalid)?
-			}
+			let link: LocLinkOf<T> = link.try_into().map_err(|_| Error::<T>::LocLinkInvalid)?;
 
 			if !<LocMap<T>>::contains_key(&loc_id) {
 				Err(Error::<T>::NotFound)?
@@ -554,78 +1160,511 @@ pub mod pallet {
 					Err(Error::<T>::CannotMutateVoid)?
 				} else if !<LocMap<T>>::contains_key(&link.id) {
 					Err(Error::<T>::LinkedLocNotFound)?
+				} else if loc.links_count >= T::MaxLocLinks::get() {
+					Err(Error::<T>::TooManyLinks)?
 				} else {
+					let deposit_size = link.encoded_size();
+					let target = link.id;
+					<LocLinksMap<T>>::insert(&loc_id, loc.links_count, link);
 					<LocMap<T>>::mutate(loc_id, |loc| {
 						let mutable_loc = loc.as_mut().unwrap();
-						mutable_loc.links.push(link);
+						mutable_loc.links_count += 1;
 					});
+					Self::reserve_deposit(&who, &loc_id, deposit_size)?;
+					Self::add_reference(&target, &loc_id);
+
+					Self::deposit_event(Event::LinkAdded(loc_id, target, who));
 					Ok(().into())
 				}
 			}
 		}
 
-		/// Close LOC.
-		#[pallet::weight(T::WeightInfo::close())]
-		pub fn close(
-			origin: OriginFor<T>,
-			#[pallet::compact] loc_id: T::LocId,
-		) -> DispatchResultWithPostInfo {
-			Self::do_close(origin, loc_id, None)
-		}
-
-		/// Close and seal LOC.
-		#[pallet::weight(T::WeightInfo::close())]
-		pub fn close_and_seal(
+		/// Delete LOC metadata
+		#[pallet::weight(T::WeightInfo::delete_metadata(T::MaxLocMetadata::get()))]
+		pub fn delete_metadata(
 			origin: OriginFor<T>,
 			#[pallet::compact] loc_id: T::LocId,
-			seal: <T as Config>::Hash,
+			name: Vec<u8>,
 		) -> DispatchResultWithPostInfo {
-			Self::do_close(origin, loc_id, Some(seal))
-		}
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
 
-		/// Make a LOC void.
-		#[pallet::weight(T::WeightInfo::make_void())]
-		pub fn make_void(
+			if !<LocMap<T>>::contains_key(&loc_id) {
+				Err(Error::<T>::NotFound)?
+			} else {
+				let loc = <LocMap<T>>::get(&loc_id).unwrap();
+				if loc.owner != who {
+					Err(Error::<T>::Unauthorized)?
+				} else if loc.closed {
+					Err(Error::<T>::CannotMutate)?
+				} else if loc.void_info.is_some() {
+					Err(Error::<T>::CannotMutateVoid)?
+				} else {
+					match Self::find_metadata_item(&loc_id, &loc, &name) {
+						None => Err(Error::<T>::MetadataItemNotFound)?,
+						Some((index, item)) => {
+							let depositor = if item.acknowledged { &loc.owner } else { &item.submitter };
+							Self::release_deposit(depositor, &loc_id, item.encoded_size());
+							Self::remove_loc_item::<LocMetadataMap<T>, _>(&loc_id, index, loc.metadata_count);
+							<LocMap<T>>::mutate(loc_id, |loc| {
+								let mutable_loc = loc.as_mut().unwrap();
+								mutable_loc.metadata_count = mutable_loc.metadata_count.saturating_sub(1);
+							});
+
+							Self::deposit_event(Event::MetadataDeleted(loc_id, name));
+							Ok(Some(T::WeightInfo::delete_metadata(loc.metadata_count)).into())
+						}
+					}
+				}
+			}
+		}
+
+		/// Delete file from LOC
+		#[pallet::weight(T::WeightInfo::delete_file(T::MaxLocFiles::get()))]
+		pub fn delete_file(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+			hash: <T as Config>::Hash,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+
+			if !<LocMap<T>>::contains_key(&loc_id) {
+				Err(Error::<T>::NotFound)?
+			} else {
+				let loc = <LocMap<T>>::get(&loc_id).unwrap();
+				if loc.owner != who {
+					Err(Error::<T>::Unauthorized)?
+				} else if loc.closed {
+					Err(Error::<T>::CannotMutate)?
+				} else if loc.void_info.is_some() {
+					Err(Error::<T>::CannotMutateVoid)?
+				} else {
+					let files = Self::loc_items::<LocFilesMap<T>, _>(&loc_id, loc.files_count);
+					match files.iter().position(|file| file.hash == hash) {
+						None => Err(Error::<T>::FileNotFound)?,
+						Some(index) => {
+							let file = &files[index];
+							let depositor = if file.acknowledged { &loc.owner } else { &file.submitter };
+							Self::release_deposit(depositor, &loc_id, file.encoded_size());
+							Self::remove_loc_item::<LocFilesMap<T>, _>(&loc_id, index as u32, loc.files_count);
+							<LocMap<T>>::mutate(loc_id, |loc| {
+								let mutable_loc = loc.as_mut().unwrap();
+								mutable_loc.files_count = mutable_loc.files_count.saturating_sub(1);
+							});
+							if files.iter().filter(|file| file.hash == hash).count() == 1 {
+								Self::unindex_file_hash(&hash, &loc_id, None);
+							}
+
+							Self::deposit_event(Event::FileDeleted(loc_id, hash));
+							Ok(Some(T::WeightInfo::delete_file(loc.files_count)).into())
+						}
+					}
+				}
+			}
+		}
+
+		/// Delete a link from LOC
+		#[pallet::weight(T::WeightInfo::delete_link(T::MaxLocLinks::get()))]
+		pub fn delete_link(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+			target: T::LocId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+
+			if !<LocMap<T>>::contains_key(&loc_id) {
+				Err(Error::<T>::NotFound)?
+			} else {
+				let loc = <LocMap<T>>::get(&loc_id).unwrap();
+				if loc.owner != who {
+					Err(Error::<T>::Unauthorized)?
+				} else if loc.closed {
+					Err(Error::<T>::CannotMutate)?
+				} else if loc.void_info.is_some() {
+					Err(Error::<T>::CannotMutateVoid)?
+				} else {
+					match Self::find_loc_item::<LocLinksMap<T>, _, _>(&loc_id, loc.links_count, |link| link.id == target) {
+						None => Err(Error::<T>::LinkNotFound)?,
+						Some((index, link)) => {
+							let deposit_size = link.encoded_size();
+							Self::remove_loc_item::<LocLinksMap<T>, _>(&loc_id, index, loc.links_count);
+							<LocMap<T>>::mutate(loc_id, |loc| {
+								let mutable_loc = loc.as_mut().unwrap();
+								mutable_loc.links_count = mutable_loc.links_count.saturating_sub(1);
+							});
+							Self::release_deposit(&loc.owner, &loc_id, deposit_size);
+							Self::remove_reference(&target, &loc_id);
+
+							Self::deposit_event(Event::LinkDeleted(loc_id, target));
+							Ok(Some(T::WeightInfo::delete_link(loc.links_count)).into())
+						}
+					}
+				}
+			}
+		}
+
+		/// Acknowledge LOC metadata submitted by the requester
+		#[pallet::weight(T::WeightInfo::acknowledge_metadata(T::MaxLocMetadata::get()))]
+		pub fn acknowledge_metadata(
 			origin: OriginFor<T>,
 			#[pallet::compact] loc_id: T::LocId,
+			name: Vec<u8>,
 		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+
+			if !<LocMap<T>>::contains_key(&loc_id) {
+				Err(Error::<T>::NotFound)?
+			} else {
+				let loc = <LocMap<T>>::get(&loc_id).unwrap();
+				if loc.owner != who {
+					Err(Error::<T>::Unauthorized)?
+				} else if loc.closed {
+					Err(Error::<T>::CannotMutate)?
+				} else if loc.void_info.is_some() {
+					Err(Error::<T>::CannotMutateVoid)?
+				} else {
+					match Self::find_metadata_item(&loc_id, &loc, &name) {
+						None => Err(Error::<T>::MetadataItemNotFound)?,
+						Some((index, item)) => {
+							if item.acknowledged {
+								Err(Error::<T>::ItemAlreadyAcknowledged)?
+							}
+							Self::release_deposit(&item.submitter, &loc_id, item.encoded_size());
+							Self::reserve_deposit(&who, &loc_id, item.encoded_size())?;
+							<LocMetadataMap<T>>::mutate(&loc_id, index, |item| {
+								let mutable_item = item.as_mut().unwrap();
+								mutable_item.acknowledged = true;
+							});
+
+							Self::deposit_event(Event::MetadataAcknowledged(loc_id, name));
+							Ok(Some(T::WeightInfo::acknowledge_metadata(loc.metadata_count)).into())
+						}
+					}
+				}
+			}
+		}
+
+		/// Acknowledge a file submitted by the requester
+		#[pallet::weight(T::WeightInfo::acknowledge_file(T::MaxLocFiles::get()))]
+		pub fn acknowledge_file(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+			hash: <T as Config>::Hash,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+
+			if !<LocMap<T>>::contains_key(&loc_id) {
+				Err(Error::<T>::NotFound)?
+			} else {
+				let loc = <LocMap<T>>::get(&loc_id).unwrap();
+				if loc.owner != who {
+					Err(Error::<T>::Unauthorized)?
+				} else if loc.closed {
+					Err(Error::<T>::CannotMutate)?
+				} else if loc.void_info.is_some() {
+					Err(Error::<T>::CannotMutateVoid)?
+				} else {
+					match Self::find_loc_item::<LocFilesMap<T>, _, _>(&loc_id, loc.files_count, |file| file.hash == hash) {
+						None => Err(Error::<T>::FileNotFound)?,
+						Some((index, file)) => {
+							if file.acknowledged {
+								Err(Error::<T>::ItemAlreadyAcknowledged)?
+							}
+							Self::release_deposit(&file.submitter, &loc_id, file.encoded_size());
+							Self::reserve_deposit(&who, &loc_id, file.encoded_size())?;
+							<LocFilesMap<T>>::mutate(&loc_id, index, |file| {
+								let mutable_file = file.as_mut().unwrap();
+								mutable_file.acknowledged = true;
+							});
+
+							Self::deposit_event(Event::FileAcknowledged(loc_id, hash));
+							Ok(Some(T::WeightInfo::acknowledge_file(loc.files_count)).into())
+						}
+					}
+				}
+			}
+		}
+
+		/// Close LOC.
+		#[pallet::weight(T::WeightInfo::close(T::MaxLocMetadata::get().saturating_add(T::MaxLocFiles::get())))]
+		pub fn close(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			Self::do_close(origin, loc_id, None)
+		}
+
+		/// Close and seal LOC.
+		#[pallet::weight(T::WeightInfo::close_and_seal(T::MaxLocMetadata::get().saturating_add(T::MaxLocFiles::get())))]
+		pub fn close_and_seal(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+			seal: <T as Config>::Hash,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			Self::do_close(origin, loc_id, Some(seal))
+		}
+
+		/// Make a LOC void.
+		#[pallet::weight(T::WeightInfo::make_void().saturating_add(T::DbWeight::get().reads(MAX_VOID_DEPENDENTS as u64)))]
+		pub fn make_void(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
 			Self::do_make_void(origin, loc_id, None)
 		}
 
 		/// Make a LOC void and provide a replacer.
-		#[pallet::weight(T::WeightInfo::make_void_and_replace())]
+		#[pallet::weight(T::WeightInfo::make_void_and_replace().saturating_add(T::DbWeight::get().reads(MAX_VOID_DEPENDENTS as u64)))]
 		pub fn make_void_and_replace(
 			origin: OriginFor<T>,
 			#[pallet::compact] loc_id: T::LocId,
 			#[pallet::compact] replacer_loc_id: T::LocId,
 		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
 			Self::do_make_void(origin, loc_id, Some(replacer_loc_id))
 		}
 
+		/// Transfer the ownership of a LOC to another legal officer. Allowed to the current owner or to the force origin.
+		#[pallet::weight(T::WeightInfo::transfer_ownership())]
+		pub fn transfer_ownership(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+			new_owner: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = Self::ensure_owner_or_force(origin)?;
+			Self::ensure_legal_officer(&new_owner)?;
+
+			if !<LocMap<T>>::contains_key(&loc_id) {
+				Err(Error::<T>::NotFound)?
+			} else {
+				let loc = <LocMap<T>>::get(&loc_id).unwrap();
+				if who.is_some() && who.unwrap() != loc.owner {
+					Err(Error::<T>::Unauthorized)?
+				}
+				Self::do_transfer_ownership(&loc_id, loc, &new_owner)?;
+				Ok(().into())
+			}
+		}
+
+		/// Transfer the ownership of at most `limit` LOCs of a legal officer to another legal officer.
+		/// Allowed to the current owner or to the force origin. Only the transferred LOCs are charged.
+		#[pallet::weight(T::WeightInfo::transfer_all_ownership(*limit))]
+		pub fn transfer_all_ownership(
+			origin: OriginFor<T>,
+			previous_owner: T::AccountId,
+			new_owner: T::AccountId,
+			limit: u32,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = Self::ensure_owner_or_force(origin)?;
+			if who.is_some() && who.unwrap() != previous_owner {
+				Err(Error::<T>::Unauthorized)?
+			}
+			Self::ensure_legal_officer(&new_owner)?;
+
+			let loc_ids: Vec<T::LocId> = <LocsByOwner<T>>::iter_key_prefix(&previous_owner)
+				.take(limit as usize)
+				.collect();
+			for loc_id in loc_ids.iter() {
+				let loc = <LocMap<T>>::get(loc_id).ok_or(Error::<T>::NotFound)?;
+				Self::do_transfer_ownership(loc_id, loc, &new_owner)?;
+			}
+			let transferred = loc_ids.len() as u32;
+			Self::deposit_event(Event::OwnershipBulkTransferred(previous_owner, new_owner, transferred));
+			Ok(Some(T::WeightInfo::transfer_all_ownership(transferred)).into())
+		}
+
+		/// Make a collection item void, optionally providing a replacer item of the same collection.
+		#[pallet::weight(T::WeightInfo::make_item_void())]
+		pub fn make_item_void(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_loc_id: T::LocId,
+			item_id: T::CollectionItemId,
+			replacer_item_id: Option<T::CollectionItemId>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+
+			if !<LocMap<T>>::contains_key(&collection_loc_id) {
+				Err(Error::<T>::NotFound)?
+			} else {
+				let collection_loc = <LocMap<T>>::get(&collection_loc_id).unwrap();
+				if collection_loc.owner != who {
+					Err(Error::<T>::Unauthorized)?
+				}
+				let item = <CollectionItemsMap<T>>::get(&collection_loc_id, &item_id)
+					.ok_or(Error::<T>::CollectionItemNotFound)?;
+				if item.void_info.is_some() {
+					Err(Error::<T>::AlreadyVoid)?
+				}
+
+				if let Some(replacer) = replacer_item_id {
+					let replacer_item = <CollectionItemsMap<T>>::get(&collection_loc_id, &replacer)
+						.ok_or(Error::<T>::ReplacerItemNotFound)?;
+					if replacer_item.void_info.is_some() {
+						Err(Error::<T>::ReplacerItemAlreadyVoid)?
+					}
+					if replacer_item.replacer_of.is_some() {
+						Err(Error::<T>::ReplacerItemAlreadyReplacing)?
+					}
+				}
+			}
+
+			let item_void_info = CollectionItemVoidInfo {
+				replacer: replacer_item_id,
+			};
+			<CollectionItemsMap<T>>::mutate(collection_loc_id, item_id, |item| {
+				let mutable_item = item.as_mut().unwrap();
+				mutable_item.void_info = Some(item_void_info);
+			});
+			if let Some(replacer) = replacer_item_id {
+				<CollectionItemsMap<T>>::mutate(collection_loc_id, replacer, |replacer_item| {
+					let mutable_replacer_item = replacer_item.as_mut().unwrap();
+					mutable_replacer_item.replacer_of = Some(item_id);
+				});
+			}
+			Self::deposit_event(Event::ItemVoid(collection_loc_id, item_id));
+			Ok(().into())
+		}
+
 		/// Adds an item to a collection
-		#[pallet::weight(T::WeightInfo::add_collection_item())]
+		#[pallet::weight(T::WeightInfo::add_collection_item(item_files.len() as u32))]
 		pub fn add_collection_item(
 			origin: OriginFor<T>,
 			#[pallet::compact] collection_loc_id: T::LocId,
 			item_id: T::CollectionItemId,
 			item_description: Vec<u8>,
-			item_files: Vec<CollectionItemFileOf<T>>,
-			item_token: Option<CollectionItemToken>,
+			item_files: Vec<CollectionItemFileParamsOf<T>>,
+			item_token: Option<CollectionItemTokenParams>,
 			restricted_delivery: bool,
 		) -> DispatchResultWithPostInfo { Self::do_add_collection_item(origin, collection_loc_id, item_id, item_description, item_files, item_token, restricted_delivery, Vec::new()) }
 
 		/// Adds an item with terms and conditions to a collection
-		#[pallet::weight(T::WeightInfo::add_collection_item())]
+		#[pallet::weight(T::WeightInfo::add_collection_item_with_terms_and_conditions(item_files.len() as u32, terms_and_conditions.len() as u32))]
 		pub fn add_collection_item_with_terms_and_conditions(
 			origin: OriginFor<T>,
 			#[pallet::compact] collection_loc_id: T::LocId,
 			item_id: T::CollectionItemId,
 			item_description: Vec<u8>,
-			item_files: Vec<CollectionItemFileOf<T>>,
-			item_token: Option<CollectionItemToken>,
+			item_files: Vec<CollectionItemFileParamsOf<T>>,
+			item_token: Option<CollectionItemTokenParams>,
 			restricted_delivery: bool,
-			terms_and_conditions: Vec<TermsAndConditionsElement<<T as pallet::Config>::LocId>>,
+			terms_and_conditions: Vec<TermsAndConditionsElementParamsOf<T>>,
 		) -> DispatchResultWithPostInfo { Self::do_add_collection_item(origin, collection_loc_id, item_id, item_description, item_files, item_token, restricted_delivery, terms_and_conditions) }
+
+		/// Adds several items to a collection, either all of them or none
+		#[pallet::weight(T::WeightInfo::add_collection_items(
+			items.len() as u32,
+			items.iter().fold(0u32, |total, item| total.saturating_add(item.files.len() as u32)),
+			items.iter().fold(0u32, |total, item| total.saturating_add(item.terms_and_conditions.len() as u32)),
+		))]
+		pub fn add_collection_items(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_loc_id: T::LocId,
+			items: BoundedVec<CollectionItemParamsOf<T>, T::MaxItemsPerBatch>,
+		) -> DispatchResultWithPostInfo { Self::do_add_collection_items(origin, collection_loc_id, items) }
+
+		/// Replaces an item of a collection, keeping the previous version as a revision
+		#[pallet::weight(T::WeightInfo::update_collection_item(item_files.len() as u32, terms_and_conditions.len() as u32)
+			.saturating_add(Pallet::<T>::previous_revision_weight(T::MaxCollectionItemFiles::get(), T::MaxCollectionItemTermsAndConditions::get())))]
+		pub fn update_collection_item(
+			origin: OriginFor<T>,
+			#[pallet::compact] collection_loc_id: T::LocId,
+			item_id: T::CollectionItemId,
+			item_description: Vec<u8>,
+			item_files: Vec<CollectionItemFileParamsOf<T>>,
+			item_token: Option<CollectionItemTokenParams>,
+			restricted_delivery: bool,
+			terms_and_conditions: Vec<TermsAndConditionsElementParamsOf<T>>,
+		) -> DispatchResultWithPostInfo { Self::do_update_collection_item(origin, collection_loc_id, item_id, item_description, item_files, item_token, restricted_delivery, terms_and_conditions) }
+
+		/// Creates a new Polkadot Identity LOC with an ID allocated from NextLocId.
+		#[pallet::weight(T::WeightInfo::create_polkadot_identity_loc().saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
+		pub fn create_polkadot_identity_loc_with_next_id(
+			origin: OriginFor<T>,
+			requester_account_id: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			T::CreateOrigin::ensure_origin(origin.clone())?;
+			let who = ensure_signed(origin)?;
+			Self::do_create_polkadot_identity_loc(who, Self::allocate_loc_id()?, requester_account_id)
+		}
+
+		/// Creates a new logion Identity LOC with an ID allocated from NextLocId.
+		#[pallet::weight(T::WeightInfo::create_logion_identity_loc().saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
+		pub fn create_logion_identity_loc_with_next_id(
+			origin: OriginFor<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			T::CreateOrigin::ensure_origin(origin.clone())?;
+			let who = ensure_signed(origin)?;
+			Self::do_create_logion_identity_loc(who, Self::allocate_loc_id()?)
+		}
+
+		/// Creates a new Polkadot Transaction LOC with an ID allocated from NextLocId.
+		#[pallet::weight(T::WeightInfo::create_polkadot_transaction_loc().saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
+		pub fn create_polkadot_transaction_loc_with_next_id(
+			origin: OriginFor<T>,
+			requester_account_id: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			T::CreateOrigin::ensure_origin(origin.clone())?;
+			let who = ensure_signed(origin)?;
+			Self::do_create_polkadot_transaction_loc(who, Self::allocate_loc_id()?, requester_account_id)
+		}
+
+		/// Creates a new logion Transaction LOC with an ID allocated from NextLocId.
+		#[pallet::weight(T::WeightInfo::create_logion_transaction_loc().saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
+		pub fn create_logion_transaction_loc_with_next_id(
+			origin: OriginFor<T>,
+			requester_loc_id: T::LocId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			T::CreateOrigin::ensure_origin(origin.clone())?;
+			let who = ensure_signed(origin)?;
+			Self::do_create_logion_transaction_loc(who, Self::allocate_loc_id()?, requester_loc_id)
+		}
+
+		/// Creates a new Collection LOC with an ID allocated from NextLocId.
+		#[pallet::weight(T::WeightInfo::create_collection_loc().saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
+		pub fn create_collection_loc_with_next_id(
+			origin: OriginFor<T>,
+			requester_account_id: T::AccountId,
+			collection_last_block_submission: Option<T::BlockNumber>,
+			collection_max_size: Option<u32>,
+			collection_can_upload: bool,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			T::CreateOrigin::ensure_origin(origin.clone())?;
+			let who = ensure_signed(origin)?;
+			Self::do_create_collection_loc(who, Self::allocate_loc_id()?, requester_account_id, collection_last_block_submission, collection_max_size, collection_can_upload)
+		}
+
+		/// Pays the fee due by the requester of a LOC to its owner.
+		#[pallet::weight(T::WeightInfo::pay_loc_fee())]
+		pub fn pay_loc_fee(
+			origin: OriginFor<T>,
+			#[pallet::compact] loc_id: T::LocId,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+
+			let loc = <LocMap<T>>::get(&loc_id).ok_or(Error::<T>::NotFound)?;
+			if !Self::is_requester(&who, &loc) {
+				Err(Error::<T>::Unauthorized)?
+			}
+			let fee = <LocFees<T>>::take(&loc_id).ok_or(Error::<T>::NoLocFeeDue)?;
+			Self::charge_fee(&loc_id, &who, &loc.owner, fee)?;
+			Ok(().into())
+		}
 	}
 
 	impl<T: Config> LocQuery<<T as frame_system::Config>::AccountId> for Pallet<T> {
@@ -633,12 +1672,324 @@ pub mod pallet {
 			account: &<T as frame_system::Config>::AccountId,
 			legal_officers: &Vec<<T as frame_system::Config>::AccountId>
 		) -> bool {
-			Self::has_closed_identity_loc(account, &legal_officers[0]) && Self::has_closed_identity_loc(account, &legal_officers[1])
+			legal_officers.len() >= 2
+				&& legal_officers[..2].iter().all(|legal_officer| <ClosedIdentityLocs<T>>::contains_key(account, legal_officer))
 		}
 	}
 
 	impl<T: Config> Pallet<T> {
 
+		/// Returns the LOC with given ID, if any.
+		pub fn get_loc(loc_id: T::LocId) -> Option<LegalOfficerCaseOf<T>> {
+			<LocMap<T>>::get(loc_id)
+		}
+
+		/// Returns the metadata items of given LOC, in the order they were added.
+		pub fn get_loc_metadata(loc_id: T::LocId) -> Vec<MetadataItemOf<T>> {
+			let count = <LocMap<T>>::get(&loc_id).map_or(0, |loc| loc.metadata_count);
+			Self::loc_items::<LocMetadataMap<T>, _>(&loc_id, count)
+		}
+
+		/// Returns the files of given LOC, in the order they were added.
+		pub fn get_loc_files(loc_id: T::LocId) -> Vec<FileOf<T>> {
+			let count = <LocMap<T>>::get(&loc_id).map_or(0, |loc| loc.files_count);
+			Self::loc_items::<LocFilesMap<T>, _>(&loc_id, count)
+		}
+
+		/// Returns the links of given LOC, in the order they were added.
+		pub fn get_loc_links(loc_id: T::LocId) -> Vec<LocLinkOf<T>> {
+			let count = <LocMap<T>>::get(&loc_id).map_or(0, |loc| loc.links_count);
+			Self::loc_items::<LocLinksMap<T>, _>(&loc_id, count)
+		}
+
+		/// Returns the item of a collection LOC, if any.
+		pub fn get_collection_item(collection_loc_id: T::LocId, item_id: T::CollectionItemId) -> Option<CollectionItemOf<T>> {
+			<CollectionItemsMap<T>>::get(collection_loc_id, item_id)
+		}
+
+		/// Returns at most `limit` IDs of the LOCs requested by given account, starting after `start_after` if any.
+		pub fn get_account_locs(account: T::AccountId, start_after: Option<T::LocId>, limit: u32) -> Vec<T::LocId> {
+			let legacy_loc_ids = <AccountLocsMap<T>>::get(&account).unwrap_or_default();
+			Self::paginate_loc_ids(legacy_loc_ids, start_after, limit, |start_after| match start_after {
+				Some(last_loc_id) => <AccountLocs<T>>::iter_key_prefix_from(&account, <AccountLocs<T>>::hashed_key_for(&account, &last_loc_id)),
+				None => <AccountLocs<T>>::iter_key_prefix(&account),
+			})
+		}
+
+		/// Returns the number of LOCs requested by given account.
+		pub fn get_account_locs_count(account: T::AccountId) -> u32 {
+			let legacy_count = <AccountLocsMap<T>>::decode_len(&account).unwrap_or(0) as u32;
+			<AccountLocsCount<T>>::get(&account).saturating_add(legacy_count)
+		}
+
+		/// Returns at most `limit` IDs of the LOCs owned by given account, starting after `start_after` if any.
+		/// IDs are returned in storage order, the last ID of a page is expected as `start_after` for the next one.
+		pub fn get_owner_locs(owner: T::AccountId, start_after: Option<T::LocId>, limit: u32) -> Vec<T::LocId> {
+			let loc_ids = match start_after {
+				Some(last_loc_id) => <LocsByOwner<T>>::iter_key_prefix_from(&owner, <LocsByOwner<T>>::hashed_key_for(&owner, &last_loc_id)),
+				None => <LocsByOwner<T>>::iter_key_prefix(&owner),
+			};
+			loc_ids.take(limit as usize).collect()
+		}
+
+		/// Returns the IDs of the closed Identity LOCs of given account owned by given legal officers,
+		/// provided that at least `threshold` distinct legal officers own one. Returns `None` otherwise.
+		pub fn find_closed_identity_locs(
+			account: &T::AccountId,
+			legal_officers: &Vec<T::AccountId>,
+			threshold: u32,
+		) -> Option<Vec<T::LocId>> {
+			let loc_ids: Vec<T::LocId> = legal_officers.iter()
+				.collect::<BTreeSet<_>>()
+				.into_iter()
+				.filter_map(|legal_officer| <ClosedIdentityLocs<T>>::get(account, legal_officer))
+				.collect();
+			if loc_ids.len() >= threshold as usize {
+				Some(loc_ids)
+			} else {
+				None
+			}
+		}
+
+		/// Returns the IDs of the LOCs linking to given LOC or using it as terms and conditions.
+		pub fn get_linked_from(loc_id: T::LocId) -> Vec<T::LocId> {
+			<LinkedFrom<T>>::iter_key_prefix(loc_id).collect()
+		}
+
+		/// Returns the LOCs and collection items (if any) which include a file with given hash.
+		pub fn find_by_file_hash(hash: <T as Config>::Hash) -> Vec<(T::LocId, Option<T::CollectionItemId>)> {
+			<FileHashIndex<T>>::iter_key_prefix(hash).collect()
+		}
+
+		/// Returns at most `limit` IDs of the LOCs requested by given logion Identity LOC, starting after `start_after` if any.
+		pub fn get_identity_loc_locs(identity_loc_id: T::LocId, start_after: Option<T::LocId>, limit: u32) -> Vec<T::LocId> {
+			let legacy_loc_ids = <IdentityLocLocsMap<T>>::get(&identity_loc_id).unwrap_or_default();
+			Self::paginate_loc_ids(legacy_loc_ids, start_after, limit, |start_after| match start_after {
+				Some(last_loc_id) => <IdentityLocLocs<T>>::iter_key_prefix_from(&identity_loc_id, <IdentityLocLocs<T>>::hashed_key_for(&identity_loc_id, &last_loc_id)),
+				None => <IdentityLocLocs<T>>::iter_key_prefix(&identity_loc_id),
+			})
+		}
+
+		/// Returns the number of LOCs requested by given logion Identity LOC.
+		pub fn get_identity_loc_locs_count(identity_loc_id: T::LocId) -> u32 {
+			let legacy_count = <IdentityLocLocsMap<T>>::decode_len(&identity_loc_id).unwrap_or(0) as u32;
+			<IdentityLocLocsCount<T>>::get(&identity_loc_id).saturating_add(legacy_count)
+		}
+
+		/// Pages through the LOC IDs of a legacy list (not yet migrated) followed by the ones of a double map.
+		fn paginate_loc_ids<I, F>(
+			legacy_loc_ids: Vec<T::LocId>,
+			start_after: Option<T::LocId>,
+			limit: u32,
+			loc_ids_from: F,
+		) -> Vec<T::LocId>
+		where
+			I: Iterator<Item = T::LocId>,
+			F: FnOnce(Option<T::LocId>) -> I,
+		{
+			let limit = limit as usize;
+			let (legacy_page, start_after) = match start_after {
+				None => (legacy_loc_ids, None),
+				Some(last_loc_id) => match legacy_loc_ids.iter().position(|loc_id| *loc_id == last_loc_id) {
+					Some(index) => (legacy_loc_ids[index + 1..].to_vec(), None),
+					None => (Vec::new(), Some(last_loc_id)),
+				},
+			};
+			let mut page: Vec<T::LocId> = legacy_page.into_iter().take(limit).collect();
+			if page.len() < limit {
+				page.extend(loc_ids_from(start_after).take(limit - page.len()));
+			}
+			page
+		}
+
+		/// Runs the ongoing multi-block migration as long as given weight allows it.
+		/// The storage version is upgraded and the cursor removed once no entry is left.
+		fn run_multi_block_migration(cursor: MigrationCursor, remaining_weight: Weight) -> Weight {
+			let db_weight = T::DbWeight::get();
+			let available = remaining_weight.ref_time().saturating_sub(db_weight.reads_writes(1, 1).ref_time());
+			if available == 0 {
+				return Weight::zero();
+			}
+			let mut budget = MigrationBudget::new(db_weight, Weight::from_ref_time(available));
+			match T::MultiBlockMigration::translate_entries(cursor, &mut budget) {
+				Some(cursor) => {
+					<OngoingMigration<T>>::put(cursor);
+					budget.consumed_weight().saturating_add(db_weight.writes(1))
+				},
+				None => {
+					<OngoingMigration<T>>::kill();
+					let storage_version = Self::on_chain_storage_version();
+					if storage_version == T::MultiBlockMigration::TARGET_VERSION {
+						log::info!("✅ {:?} multi-block migration successfully executed", T::MultiBlockMigration::NAME);
+					} else {
+						log::error!("❗ {:?} multi-block migration stopped at version {:?}", T::MultiBlockMigration::NAME, storage_version);
+					}
+					budget.consumed_weight().saturating_add(db_weight.reads_writes(1, 1))
+				},
+			}
+		}
+
+		/// Rejects calls while a multi-block migration is in progress, or when storage is not at the current version
+		/// (e.g. after a refused migration), so that calls never run on a previous storage layout.
+		fn ensure_no_migration_in_progress() -> Result<(), Error<T>> {
+			if <OngoingMigration<T>>::exists() || Self::on_chain_storage_version() < STORAGE_VERSION {
+				Err(Error::<T>::MigrationInProgress)
+			} else {
+				Ok(())
+			}
+		}
+
+		/// Returns the number of items in a collection LOC, if any.
+		pub fn get_collection_size(collection_loc_id: T::LocId) -> Option<CollectionSize> {
+			<CollectionSizeMap<T>>::get(collection_loc_id)
+		}
+
+		/// Returns the deposit required to store an entry of given encoded size.
+		pub fn deposit_for(encoded_size: usize) -> BalanceOf<T> {
+			let bytes: BalanceOf<T> = (encoded_size as u32).into();
+			T::DepositBase::get().saturating_add(T::DepositPerByte::get().saturating_mul(bytes))
+		}
+
+		fn reserve_deposit(
+			depositor: &T::AccountId,
+			loc_id: &T::LocId,
+			encoded_size: usize,
+		) -> DispatchResult {
+			let deposit = Self::deposit_for(encoded_size);
+			T::Currency::reserve(depositor, deposit).map_err(|_| Error::<T>::InsufficientFunds)?;
+			<DepositsMap<T>>::mutate(loc_id, depositor, |total| *total = total.saturating_add(deposit));
+			Ok(())
+		}
+
+		pub(crate) fn release_deposit(
+			depositor: &T::AccountId,
+			loc_id: &T::LocId,
+			encoded_size: usize,
+		) {
+			let reserved = <DepositsMap<T>>::get(loc_id, depositor);
+			let released = Self::deposit_for(encoded_size).min(reserved);
+			T::Currency::unreserve(depositor, released);
+			if released == reserved {
+				<DepositsMap<T>>::remove(loc_id, depositor);
+			} else {
+				<DepositsMap<T>>::insert(loc_id, depositor, reserved.saturating_sub(released));
+			}
+		}
+
+		/// Records the fee due by the requester of a new LOC, if any.
+		fn set_loc_fee(loc_id: &T::LocId, fee: BalanceOf<T>) {
+			if !fee.is_zero() {
+				<LocFees<T>>::insert(loc_id, fee);
+			}
+		}
+
+		/// Charges `fee` to `payer`, who must be the signer of the call. The legal officer gets the fee
+		/// minus the treasury share, which is nothing to transfer when the legal officer pays.
+		fn charge_fee(
+			loc_id: &T::LocId,
+			payer: &T::AccountId,
+			legal_officer: &T::AccountId,
+			fee: BalanceOf<T>,
+		) -> DispatchResult {
+			if fee.is_zero() {
+				return Ok(());
+			}
+			let treasury_fee = T::TreasuryShare::get() * fee;
+			let legal_officer_fee = fee.saturating_sub(treasury_fee);
+			if payer != legal_officer {
+				T::Currency::transfer(payer, legal_officer, legal_officer_fee, ExistenceRequirement::KeepAlive)
+					.map_err(|_| Error::<T>::InsufficientFunds)?;
+			}
+			if !treasury_fee.is_zero() {
+				let imbalance = T::Currency::withdraw(payer, treasury_fee, WithdrawReasons::FEE, ExistenceRequirement::KeepAlive)
+					.map_err(|_| Error::<T>::InsufficientFunds)?;
+				T::FeeDestination::on_unbalanced(imbalance);
+			}
+			Self::deposit_event(Event::FeesCharged(*loc_id, payer.clone(), legal_officer_fee, treasury_fee));
+			Ok(())
+		}
+
+		fn ensure_owner_or_force(origin: OriginFor<T>) -> Result<Option<T::AccountId>, DispatchError> {
+			match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => Ok(None),
+				Err(origin) => Ok(Some(ensure_signed(origin)?)),
+			}
+		}
+
+		fn ensure_legal_officer(account: &T::AccountId) -> DispatchResult {
+			T::CreateOrigin::try_origin(frame_system::RawOrigin::Signed(account.clone()).into())
+				.map(|_| ())
+				.map_err(|_| Error::<T>::NewOwnerNotLegalOfficer.into())
+		}
+
+		pub(crate) fn do_transfer_ownership(
+			loc_id: &T::LocId,
+			loc: LegalOfficerCaseOf<T>,
+			new_owner: &T::AccountId,
+		) -> DispatchResult {
+			let previous_owner = loc.owner.clone();
+			let deposit = <DepositsMap<T>>::take(loc_id, &previous_owner);
+			if !deposit.is_zero() {
+				T::Currency::reserve(new_owner, deposit).map_err(|_| Error::<T>::InsufficientFunds)?;
+				T::Currency::unreserve(&previous_owner, deposit);
+				<DepositsMap<T>>::mutate(loc_id, new_owner, |total| *total = total.saturating_add(deposit));
+			}
+
+			<LocMap<T>>::mutate(loc_id, |loc| {
+				let mutable_loc = loc.as_mut().unwrap();
+				mutable_loc.owner = new_owner.clone();
+			});
+			<LocsByOwner<T>>::remove(&previous_owner, loc_id);
+			<LocsByOwner<T>>::insert(new_owner, loc_id, ());
+			if loc.closed && loc.void_info.is_none() {
+				Self::unindex_closed_identity_loc(loc_id, &loc);
+				Self::index_closed_identity_loc(loc_id, &<LocMap<T>>::get(loc_id).unwrap());
+			}
+
+			Self::deposit_event(Event::OwnershipTransferred(*loc_id, previous_owner, new_owner.clone()));
+			Ok(())
+		}
+
+		/// Returns the index and the value of the first metadata item of given LOC with given name, if any.
+		fn find_metadata_item(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>, name: &Vec<u8>) -> Option<(u32, MetadataItemOf<T>)> {
+			Self::find_loc_item::<LocMetadataMap<T>, _, _>(loc_id, loc.metadata_count, |item| item.name[..] == name[..])
+		}
+
+		/// Returns the `count` items of a LOC item map (metadata, files or links) for given LOC, by index.
+		fn loc_items<M, V>(loc_id: &T::LocId, count: u32) -> Vec<V>
+		where
+			M: frame_support::storage::StorageDoubleMap<T::LocId, u32, V, Query = Option<V>>,
+			V: FullCodec,
+		{
+			(0..count).filter_map(|index| M::get(loc_id, index)).collect()
+		}
+
+		/// Returns the index and the value of the first of the `count` items of a LOC item map matching `predicate`, if any.
+		fn find_loc_item<M, V, F>(loc_id: &T::LocId, count: u32, predicate: F) -> Option<(u32, V)>
+		where
+			M: frame_support::storage::StorageDoubleMap<T::LocId, u32, V, Query = Option<V>>,
+			V: FullCodec,
+			F: Fn(&V) -> bool,
+		{
+			(0..count).find_map(|index| M::get(loc_id, index).filter(|item| predicate(item)).map(|item| (index, item)))
+		}
+
+		/// Removes the item with given index from the `count` items of a LOC item map, the following items being
+		/// moved down by one so that indexes keep ranging from 0 to `count - 1` excluded.
+		fn remove_loc_item<M, V>(loc_id: &T::LocId, index: u32, count: u32)
+		where
+			M: frame_support::storage::StorageDoubleMap<T::LocId, u32, V, Query = Option<V>>,
+			V: FullCodec,
+		{
+			let last_index = count.saturating_sub(1);
+			for next_index in index.saturating_add(1)..=last_index {
+				if let Some(item) = M::get(loc_id, next_index) {
+					M::insert(loc_id, next_index - 1, item);
+				}
+			}
+			M::remove(loc_id, last_index);
+		}
+
 		fn validate_submitter(
 			submitter: &T::AccountId,
 			loc: &LegalOfficerCaseOf<T>
@@ -679,22 +2030,8 @@ pub mod pallet {
 					Err(Error::<T>::AlreadyVoid)?
 				}
 
-				if replacer_loc_id.is_some() {
-					let replacer = replacer_loc_id.unwrap();
-					if !<LocMap<T>>::contains_key(&replacer) {
-						Err(Error::<T>::ReplacerLocNotFound)?
-					} else {
-						let replacer_loc = <LocMap<T>>::get(&replacer).unwrap();
-						if replacer_loc.void_info.is_some() {
-							Err(Error::<T>::ReplacerLocAlreadyVoid)?
-						}
-						if replacer_loc.replacer_of.is_some() {
-							Err(Error::<T>::ReplacerLocAlreadyReplacing)?
-						}
-						if !replacer_loc.loc_type.eq(&loc.loc_type) {
-							Err(Error::<T>::ReplacerLocWrongType)?
-						}
-					}
+				if let Some(replacer) = &replacer_loc_id {
+					Self::validate_replacer(&loc, replacer)?;
 				}
 			}
 
@@ -711,24 +2048,49 @@ pub mod pallet {
 					mutable_replacer_loc.replacer_of = Some(loc_id);
 				});
 			}
-			Self::deposit_event(Event::LocVoid(loc_id));
-			Ok(().into())
+			Self::unindex_closed_identity_loc(&loc_id, &<LocMap<T>>::get(&loc_id).unwrap());
+			Self::deposit_event(Event::LocVoid(loc_id));
+			let dependents: Vec<T::LocId> = <LinkedFrom<T>>::iter_key_prefix(loc_id)
+				.take(MAX_VOID_DEPENDENTS as usize)
+				.collect();
+			let dependents_weight = T::DbWeight::get().reads(dependents.len() as u64);
+			if !dependents.is_empty() {
+				Self::deposit_event(Event::VoidLocHasDependents(loc_id, dependents));
+			}
+			let base_weight = match replacer_loc_id {
+				Some(_) => T::WeightInfo::make_void_and_replace(),
+				None => T::WeightInfo::make_void(),
+			};
+			Ok(Some(base_weight.saturating_add(dependents_weight)).into())
+		}
+
+		pub(crate) fn index_closed_identity_loc(
+			loc_id: &T::LocId,
+			loc: &LegalOfficerCaseOf<T>,
+		) {
+			if loc.loc_type == LocType::Identity {
+				if let Requester::Account(requester) = &loc.requester {
+					<ClosedIdentityLocs<T>>::insert(requester, &loc.owner, loc_id);
+					<AllClosedIdentityLocs<T>>::insert((requester, &loc.owner), loc_id, ());
+				}
+			}
 		}
 
-		fn has_closed_identity_loc(
-			account: &<T as frame_system::Config>::AccountId,
-			legal_officer: &<T as frame_system::Config>::AccountId
-		) -> bool {
-			let value = <AccountLocsMap<T>>::get(account);
-			match value {
-				Some(loc_ids) => {
-					return loc_ids.iter().map(|id| <LocMap<T>>::get(id))
-						.filter(|option| option.is_some())
-						.map(|some| some.unwrap())
-						.find(|loc| loc.owner == *legal_officer && loc.loc_type == LocType::Identity && loc.closed)
-						.is_some();
+		/// Removes given LOC from the index of closed Identity LOCs, falling back to another closed and
+		/// non-void Identity LOC of the same requester and legal officer, if any.
+		fn unindex_closed_identity_loc(
+			loc_id: &T::LocId,
+			loc: &LegalOfficerCaseOf<T>,
+		) {
+			if let Requester::Account(requester) = &loc.requester {
+				let key = (requester.clone(), loc.owner.clone());
+				<AllClosedIdentityLocs<T>>::remove(&key, loc_id);
+				if <ClosedIdentityLocs<T>>::get(requester, &loc.owner) == Some(*loc_id) {
+					match <AllClosedIdentityLocs<T>>::iter_key_prefix(&key).next() {
+						Some(other_loc_id) => <ClosedIdentityLocs<T>>::insert(requester, &loc.owner, other_loc_id),
+						None => <ClosedIdentityLocs<T>>::remove(requester, &loc.owner),
+					}
 				}
-				None => false
 			}
 		}
 
@@ -736,28 +2098,91 @@ pub mod pallet {
 			account_id: &<T as frame_system::Config>::AccountId,
 			loc_id: &<T as Config>::LocId,
 		) {
-			if <AccountLocsMap<T>>::contains_key(account_id) {
-				<AccountLocsMap<T>>::mutate(account_id, |locs| {
-					let list = locs.as_mut().unwrap();
-					list.push(loc_id.clone());
-				});
-			} else {
-				<AccountLocsMap<T>>::insert(account_id, Vec::from([loc_id.clone()]));
-			}
+			<AccountLocs<T>>::insert(account_id, loc_id, ());
+			<AccountLocsCount<T>>::mutate(account_id, |count| *count = count.saturating_add(1));
 		}
 
 		fn link_with_identity_loc(
 			requester_loc_id: &<T as Config>::LocId,
 			loc_id: &<T as Config>::LocId,
 		) {
-			if <IdentityLocLocsMap<T>>::contains_key(requester_loc_id) {
-				<IdentityLocLocsMap<T>>::mutate(requester_loc_id, |locs| {
-					let list = locs.as_mut().unwrap();
-					list.push(loc_id.clone());
-				});
+			<IdentityLocLocs<T>>::insert(requester_loc_id, loc_id, ());
+			<IdentityLocLocsCount<T>>::mutate(requester_loc_id, |count| *count = count.saturating_add(1));
+		}
+
+		pub(crate) fn index_file_hash(
+			hash: &<T as Config>::Hash,
+			loc_id: &T::LocId,
+			item_id: Option<T::CollectionItemId>,
+		) {
+			<FileHashIndex<T>>::insert(hash, (loc_id.clone(), item_id), ());
+		}
+
+		pub(crate) fn index_collection_item_files(
+			collection_loc_id: &T::LocId,
+			item_id: &T::CollectionItemId,
+			item: &CollectionItemOf<T>,
+		) {
+			for file in item.files.iter() {
+				Self::index_file_hash(&file.hash, collection_loc_id, Some(item_id.clone()));
+			}
+		}
+
+		fn unindex_collection_item_files(
+			collection_loc_id: &T::LocId,
+			item_id: &T::CollectionItemId,
+			item: &CollectionItemOf<T>,
+		) {
+			for file in item.files.iter() {
+				Self::unindex_file_hash(&file.hash, collection_loc_id, Some(item_id.clone()));
+			}
+		}
+
+		fn unindex_file_hash(
+			hash: &<T as Config>::Hash,
+			loc_id: &T::LocId,
+			item_id: Option<T::CollectionItemId>,
+		) {
+			<FileHashIndex<T>>::remove(hash, (loc_id.clone(), item_id));
+		}
+
+		pub(crate) fn add_reference(target_loc_id: &T::LocId, source_loc_id: &T::LocId) {
+			<LinkedFrom<T>>::mutate(target_loc_id, source_loc_id, |references| *references = references.saturating_add(1));
+		}
+
+		pub(crate) fn add_terms_and_conditions_references(collection_loc_id: &T::LocId, item: &CollectionItemOf<T>) {
+			for tc in item.terms_and_conditions.iter() {
+				Self::add_reference(&tc.tc_loc, collection_loc_id);
+			}
+		}
+
+		fn remove_terms_and_conditions_references(collection_loc_id: &T::LocId, item: &CollectionItemOf<T>) {
+			for tc in item.terms_and_conditions.iter() {
+				Self::remove_reference(&tc.tc_loc, collection_loc_id);
+			}
+		}
+
+		fn remove_reference(target_loc_id: &T::LocId, source_loc_id: &T::LocId) {
+			let references = <LinkedFrom<T>>::get(target_loc_id, source_loc_id);
+			if references <= 1 {
+				<LinkedFrom<T>>::remove(target_loc_id, source_loc_id);
 			} else {
-				<IdentityLocLocsMap<T>>::insert(requester_loc_id, Vec::from([loc_id.clone()]));
+				<LinkedFrom<T>>::insert(target_loc_id, source_loc_id, references - 1);
+			}
+		}
+
+		fn validate_replacer(loc: &LegalOfficerCaseOf<T>, replacer_loc_id: &T::LocId) -> Result<(), Error<T>> {
+			let replacer_loc = <LocMap<T>>::get(replacer_loc_id).ok_or(Error::<T>::ReplacerLocNotFound)?;
+			if replacer_loc.void_info.is_some() {
+				Err(Error::<T>::ReplacerLocAlreadyVoid)?
+			}
+			if replacer_loc.replacer_of.is_some() {
+				Err(Error::<T>::ReplacerLocAlreadyReplacing)?
+			}
+			if !replacer_loc.loc_type.eq(&loc.loc_type) {
+				Err(Error::<T>::ReplacerLocWrongType)?
 			}
+			Ok(())
 		}
 
 		fn is_valid_logion_id(loc: &LegalOfficerCaseOf<T>) -> bool {
@@ -767,6 +2192,154 @@ pub mod pallet {
 				|| loc.void_info.is_some()
 		}
 
+		fn do_create_polkadot_identity_loc(
+			who: T::AccountId,
+			loc_id: T::LocId,
+			requester_account_id: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			if <LocMap<T>>::contains_key(&loc_id) {
+				Err(Error::<T>::AlreadyExists)?
+			} else {
+				let requester = RequesterOf::<T>::Account(requester_account_id.clone());
+				let loc = Self::build_open_loc(&who, &requester, LocType::Identity);
+				Self::reserve_deposit(&who, &loc_id, loc.encoded_size())?;
+
+				<LocMap<T>>::insert(loc_id, loc);
+				<LocsByOwner<T>>::insert(&who, &loc_id, ());
+				Self::advance_next_loc_id(&loc_id);
+				Self::link_with_account(&requester_account_id, &loc_id);
+				Self::set_loc_fee(&loc_id, T::IdentityLocFee::get());
+
+				Self::deposit_event(Event::LocCreated(loc_id, who, requester, LocType::Identity));
+				Ok(().into())
+			}
+		}
+
+		fn do_create_logion_identity_loc(
+			who: T::AccountId,
+			loc_id: T::LocId,
+		) -> DispatchResultWithPostInfo {
+			if <LocMap<T>>::contains_key(&loc_id) {
+				Err(Error::<T>::AlreadyExists)?
+			} else {
+				let requester = RequesterOf::<T>::None;
+				let loc = Self::build_open_loc(&who, &requester, LocType::Identity);
+				Self::reserve_deposit(&who, &loc_id, loc.encoded_size())?;
+				<LocMap<T>>::insert(loc_id, loc);
+				<LocsByOwner<T>>::insert(&who, &loc_id, ());
+				Self::advance_next_loc_id(&loc_id);
+
+				Self::deposit_event(Event::LocCreated(loc_id, who, requester, LocType::Identity));
+				Ok(().into())
+			}
+		}
+
+		fn do_create_polkadot_transaction_loc(
+			who: T::AccountId,
+			loc_id: T::LocId,
+			requester_account_id: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			if <LocMap<T>>::contains_key(&loc_id) {
+				Err(Error::<T>::AlreadyExists)?
+			} else {
+				let requester = RequesterOf::<T>::Account(requester_account_id.clone());
+				let loc = Self::build_open_loc(&who, &requester, LocType::Transaction);
+				Self::reserve_deposit(&who, &loc_id, loc.encoded_size())?;
+
+				<LocMap<T>>::insert(loc_id, loc);
+				<LocsByOwner<T>>::insert(&who, &loc_id, ());
+				Self::advance_next_loc_id(&loc_id);
+				Self::link_with_account(&requester_account_id, &loc_id);
+				Self::set_loc_fee(&loc_id, T::TransactionLocFee::get());
+
+				Self::deposit_event(Event::LocCreated(loc_id, who, requester, LocType::Transaction));
+				Ok(().into())
+			}
+		}
+
+		fn do_create_logion_transaction_loc(
+			who: T::AccountId,
+			loc_id: T::LocId,
+			requester_loc_id: T::LocId,
+		) -> DispatchResultWithPostInfo {
+			if <LocMap<T>>::contains_key(&loc_id) {
+				Err(Error::<T>::AlreadyExists)?
+			} else {
+				let requester_loc = <LocMap<T>>::get(&requester_loc_id);
+				match requester_loc {
+					None => Err(Error::<T>::UnexpectedRequester)?,
+					Some(loc) =>
+						if Self::is_valid_logion_id(&loc) {
+							Err(Error::<T>::UnexpectedRequester)?
+						} else {
+							let requester = RequesterOf::<T>::Loc(requester_loc_id.clone());
+							let new_loc = Self::build_open_loc(&who, &requester, LocType::Transaction);
+							Self::reserve_deposit(&who, &loc_id, new_loc.encoded_size())?;
+							<LocMap<T>>::insert(loc_id, new_loc);
+							<LocsByOwner<T>>::insert(&who, &loc_id, ());
+							Self::advance_next_loc_id(&loc_id);
+							Self::link_with_identity_loc(&requester_loc_id, &loc_id);
+						},
+				}
+
+				Self::deposit_event(Event::LocCreated(loc_id, who, RequesterOf::<T>::Loc(requester_loc_id), LocType::Transaction));
+				Ok(().into())
+			}
+		}
+
+		fn do_create_collection_loc(
+			who: T::AccountId,
+			loc_id: T::LocId,
+			requester_account_id: T::AccountId,
+			collection_last_block_submission: Option<T::BlockNumber>,
+			collection_max_size: Option<u32>,
+			collection_can_upload: bool,
+		) -> DispatchResultWithPostInfo {
+			Self::validate_collection_limits(&collection_last_block_submission, &collection_max_size)?;
+
+			if <LocMap<T>>::contains_key(&loc_id) {
+				Err(Error::<T>::AlreadyExists)?
+			} else {
+				let requester = RequesterOf::<T>::Account(requester_account_id.clone());
+				let loc = Self::build_open_collection_loc(
+					&who,
+					&requester,
+					collection_last_block_submission,
+					collection_max_size,
+					collection_can_upload,
+				);
+				Self::reserve_deposit(&who, &loc_id, loc.encoded_size())?;
+
+				<LocMap<T>>::insert(loc_id, loc);
+				<LocsByOwner<T>>::insert(&who, &loc_id, ());
+				Self::advance_next_loc_id(&loc_id);
+				Self::link_with_account(&requester_account_id, &loc_id);
+				Self::set_loc_fee(&loc_id, T::CollectionLocFee::get());
+
+				Self::deposit_event(Event::LocCreated(loc_id, who, requester, LocType::Collection));
+				Ok(().into())
+			}
+		}
+
+		/// Returns NextLocId and moves it to the following ID. NextLocId is never used by an existing LOC, see
+		/// `advance_next_loc_id`.
+		fn allocate_loc_id() -> Result<T::LocId, DispatchError> {
+			let loc_id = <NextLocId<T>>::get();
+			let next_loc_id = loc_id.checked_add(&One::one()).ok_or(Error::<T>::LocIdOverflow)?;
+			<NextLocId<T>>::put(next_loc_id);
+			Ok(loc_id)
+		}
+
+		/// Moves NextLocId past given LOC ID, used by a new LOC, so that allocated IDs never collide with IDs chosen
+		/// by callers.
+		pub(crate) fn advance_next_loc_id(loc_id: &T::LocId) {
+			if *loc_id >= <NextLocId<T>>::get() {
+				if let Some(next_loc_id) = loc_id.checked_add(&One::one()) {
+					<NextLocId<T>>::put(next_loc_id);
+				}
+			}
+		}
+
 		fn build_open_loc(
 			who: &T::AccountId,
 			requester: &RequesterOf<T>,
@@ -775,11 +2348,11 @@ pub mod pallet {
 			LegalOfficerCaseOf::<T> {
 				owner: who.clone(),
 				requester: requester.clone(),
-				metadata: Vec::new(),
-				files: Vec::new(),
+				metadata_count: 0,
+				files_count: 0,
 				closed: false,
 				loc_type: loc_type.clone(),
-				links: Vec::new(),
+				links_count: 0,
 				void_info: None,
 				replacer_of: None,
 				collection_last_block_submission: Option::None,
@@ -799,11 +2372,11 @@ pub mod pallet {
 			LegalOfficerCaseOf::<T> {
 				owner: who.clone(),
 				requester: requester.clone(),
-				metadata: Vec::new(),
-				files: Vec::new(),
+				metadata_count: 0,
+				files_count: 0,
 				closed: false,
 				loc_type: LocType::Collection,
-				links: Vec::new(),
+				links_count: 0,
 				void_info: None,
 				replacer_of: None,
 				collection_last_block_submission: collection_last_block_submission.clone(),
@@ -813,6 +2386,10 @@ pub mod pallet {
 			}
 		}
 
+		fn is_requester(who: &T::AccountId, loc: &LegalOfficerCaseOf<T>) -> bool {
+			match &loc.requester { Requester::Account(requester) => requester == who, _ => false }
+		}
+
 		fn can_add_item(who: &T::AccountId, collection_loc: &LegalOfficerCaseOf<T>) -> bool {
 			collection_loc.loc_type == LocType::Collection
 				&& match &collection_loc.requester { Requester::Account(requester) => requester == who, _ => false }
@@ -820,11 +2397,25 @@ pub mod pallet {
 				&& collection_loc.void_info.is_none()
 		}
 
-		fn collection_limits_reached(collection_loc_id: &T::LocId, collection_loc: &LegalOfficerCaseOf<T>) -> bool {
+		fn validate_collection_limits(
+			collection_last_block_submission: &Option<T::BlockNumber>,
+			collection_max_size: &Option<CollectionSize>,
+		) -> Result<(), Error<T>> {
+			if collection_last_block_submission.is_none() && collection_max_size.is_none() {
+				Err(Error::<T>::CollectionHasNoLimit)?
+			}
+			Ok(())
+		}
+
+		fn collection_limits_reached(collection_loc_id: &T::LocId, collection_loc: &LegalOfficerCaseOf<T>, items_to_add: CollectionSize) -> bool {
 			let collection_size = <CollectionSizeMap<T>>::get(collection_loc_id).unwrap_or(0);
+			return match collection_loc.collection_max_size { None => false, Some(limit) => collection_size.saturating_add(items_to_add) > limit }
+				|| Self::collection_submission_ended(collection_loc);
+		}
+
+		fn collection_submission_ended(collection_loc: &LegalOfficerCaseOf<T>) -> bool {
 			let current_block_number = <frame_system::Pallet<T>>::block_number();
-			return match collection_loc.collection_max_size { None => false, Some(limit) => collection_size >= limit }
-				|| match collection_loc.collection_last_block_submission { None => false, Some(last_block) => current_block_number >= last_block };
+			match collection_loc.collection_last_block_submission { None => false, Some(last_block) => current_block_number >= last_block }
 		}
 
 		fn has_unique_elements<I>(iter: I) -> bool
@@ -853,15 +2444,27 @@ pub mod pallet {
 					Err(Error::<T>::CannotMutateVoid)?
 				} else if loc.closed {
 					Err(Error::<T>::AlreadyClosed)?
+				} else if <LocMetadataMap<T>>::iter_prefix_values(&loc_id).any(|item| !item.acknowledged)
+					|| <LocFilesMap<T>>::iter_prefix_values(&loc_id).any(|file| !file.acknowledged) {
+					Err(Error::<T>::CannotCloseUnacknowledged)?
 				} else {
 					<LocMap<T>>::mutate(loc_id, |loc| {
 						let mutable_loc = loc.as_mut().unwrap();
 						mutable_loc.closed = true;
 						mutable_loc.seal = seal;
 					});
+					Self::index_closed_identity_loc(&loc_id, &loc);
 
 					Self::deposit_event(Event::LocClosed(loc_id));
-					Ok(().into())
+					let items = loc.metadata_count.saturating_add(loc.files_count);
+					let actual_weight = match seal {
+						None => T::WeightInfo::close(items),
+						Some(seal) => {
+							Self::deposit_event(Event::LocSealed(loc_id, seal));
+							T::WeightInfo::close_and_seal(items)
+						}
+					};
+					Ok(Some(actual_weight).into())
 				}
 			}
 		}
@@ -871,90 +2474,227 @@ pub mod pallet {
 			collection_loc_id: T::LocId,
 			item_id: T::CollectionItemId,
 			item_description: Vec<u8>,
-			item_files: Vec<CollectionItemFileOf<T>>,
-			item_token: Option<CollectionItemToken>,
+			item_files: Vec<CollectionItemFileParamsOf<T>>,
+			item_token: Option<CollectionItemTokenParams>,
 			restricted_delivery: bool,
-			terms_and_conditions: Vec<TermsAndConditionsElement<<T as pallet::Config>::LocId>>,
+			terms_and_conditions: Vec<TermsAndConditionsElementParamsOf<T>>,
 		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
 			let who = ensure_signed(origin)?;
 
-			if item_description.len() > T::MaxCollectionItemDescriptionSize::get() {
-				Err(Error::<T>::CollectionItemTooMuchData)?
-			}
+			let item = Self::build_collection_item(item_description, item_files, item_token, restricted_delivery, terms_and_conditions)?;
 
-			if item_token.is_some() && item_token.as_ref().unwrap().token_type.len() > T::MaxCollectionItemTokenTypeSize::get() {
-				Err(Error::<T>::CollectionItemTooMuchData)?
-			}
+			let collection_loc_option = <LocMap<T>>::get(&collection_loc_id);
+			match collection_loc_option {
+				None => Err(Error::<T>::WrongCollectionLoc)?,
+				Some(collection_loc) => {
+					if <CollectionItemsMap<T>>::contains_key(&collection_loc_id, &item_id) {
+						Err(Error::<T>::CollectionItemAlreadyExists)?
+					}
+					if ! Self::can_add_item(&who, &collection_loc) {
+						Err(Error::<T>::WrongCollectionLoc)?
+					}
+					if Self::collection_limits_reached(&collection_loc_id, &collection_loc, 1) {
+						Err(Error::<T>::CollectionLimitsReached)?
+					}
+					Self::validate_collection_item(&collection_loc, &item)?;
 
-			if item_token.is_some() && item_token.as_ref().unwrap().token_id.len() > T::MaxCollectionItemTokenIdSize::get() {
-				Err(Error::<T>::CollectionItemTooMuchData)?
+					Self::reserve_deposit(&who, &collection_loc_id, item.encoded_size())?;
+					Self::index_collection_item_files(&collection_loc_id, &item_id, &item);
+					Self::add_terms_and_conditions_references(&collection_loc_id, &item);
+					<CollectionItemsMap<T>>::insert(collection_loc_id, item_id, item);
+					let collection_size = <CollectionSizeMap<T>>::get(&collection_loc_id).unwrap_or(0);
+					<CollectionSizeMap<T>>::insert(&collection_loc_id, collection_size + 1);
+					Self::charge_fee(&collection_loc_id, &who, &collection_loc.owner, T::CollectionItemFee::get())?;
+				},
 			}
 
-			if restricted_delivery && item_token.is_none() {
-				Err(Error::<T>::MissingToken)?
-			}
+			Self::deposit_event(Event::ItemAdded(collection_loc_id, item_id));
+			Ok(().into())
+		}
 
-			if restricted_delivery && item_files.len() == 0 {
-				Err(Error::<T>::MissingFiles)?
+		fn do_add_collection_items(
+			origin: OriginFor<T>,
+			collection_loc_id: T::LocId,
+			items: BoundedVec<CollectionItemParamsOf<T>, T::MaxItemsPerBatch>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+
+			let mut new_items: Vec<(T::CollectionItemId, CollectionItemOf<T>)> = Vec::with_capacity(items.len());
+			for params in items {
+				let item = Self::build_collection_item(params.description, params.files, params.token, params.restricted_delivery, params.terms_and_conditions)?;
+				new_items.push((params.id, item));
 			}
 
 			let collection_loc_option = <LocMap<T>>::get(&collection_loc_id);
 			match collection_loc_option {
 				None => Err(Error::<T>::WrongCollectionLoc)?,
 				Some(collection_loc) => {
-					if <CollectionItemsMap<T>>::contains_key(&collection_loc_id, &item_id) {
+					let items_count = new_items.len() as CollectionSize;
+					if !Self::has_unique_elements(new_items.iter().map(|(item_id, _)| item_id.encode())) {
+						Err(Error::<T>::CollectionItemAlreadyExists)?
+					}
+					if new_items.iter().any(|(item_id, _)| <CollectionItemsMap<T>>::contains_key(&collection_loc_id, item_id)) {
 						Err(Error::<T>::CollectionItemAlreadyExists)?
 					}
 					if ! Self::can_add_item(&who, &collection_loc) {
 						Err(Error::<T>::WrongCollectionLoc)?
 					}
-					if Self::collection_limits_reached(&collection_loc_id, &collection_loc) {
+					if Self::collection_limits_reached(&collection_loc_id, &collection_loc, items_count) {
 						Err(Error::<T>::CollectionLimitsReached)?
 					}
-					if !collection_loc.collection_can_upload && item_files.len() > 0 {
-						Err(Error::<T>::CannotUpload)?
-					}
-					if collection_loc.collection_can_upload {
-						if item_files.len() == 0 {
-							Err(Error::<T>::MustUpload)?
-						} else {
-							let files_hashes: Vec<<T as Config>::Hash> = item_files.iter()
-								.map(|file| file.hash)
-								.collect();
-							if !Self::has_unique_elements(&files_hashes) {
-								Err(Error::<T>::DuplicateFile)?
-							}
-						}
+					for (_, item) in new_items.iter() {
+						Self::validate_collection_item(&collection_loc, item)?;
 					}
 
-					for terms_and_conditions_element in &terms_and_conditions {
-						if !<LocMap<T>>::contains_key(&terms_and_conditions_element.tc_loc) {
-							Err(Error::<T>::TermsAndConditionsLocNotFound)?
-						} else {
-							let tc_loc = <LocMap<T>>::get(terms_and_conditions_element.tc_loc).unwrap();
-							if tc_loc.void_info.is_some() {
-								Err(Error::<T>::TermsAndConditionsLocVoid)?
-							} else if !tc_loc.closed {
-								Err(Error::<T>::TermsAndConditionsLocNotClosed)?
-							}
-						}
+					for (item_id, item) in new_items {
+						Self::reserve_deposit(&who, &collection_loc_id, item.encoded_size())?;
+						Self::index_collection_item_files(&collection_loc_id, &item_id, &item);
+						Self::add_terms_and_conditions_references(&collection_loc_id, &item);
+						<CollectionItemsMap<T>>::insert(collection_loc_id, item_id, item);
+						Self::deposit_event(Event::ItemAdded(collection_loc_id, item_id));
 					}
-
-					let item = CollectionItem {
-						description: item_description.clone(),
-						files: item_files.clone(),
-						token: item_token.clone(),
-						restricted_delivery,
-						terms_and_conditions,
-					};
-					<CollectionItemsMap<T>>::insert(collection_loc_id, item_id, item);
 					let collection_size = <CollectionSizeMap<T>>::get(&collection_loc_id).unwrap_or(0);
-					<CollectionSizeMap<T>>::insert(&collection_loc_id, collection_size + 1);
+					<CollectionSizeMap<T>>::insert(&collection_loc_id, collection_size + items_count);
+					let fee = T::CollectionItemFee::get().saturating_mul(items_count.into());
+					Self::charge_fee(&collection_loc_id, &who, &collection_loc.owner, fee)?;
 				},
 			}
 
-			Self::deposit_event(Event::ItemAdded(collection_loc_id, item_id));
 			Ok(().into())
 		}
+
+		fn do_update_collection_item(
+			origin: OriginFor<T>,
+			collection_loc_id: T::LocId,
+			item_id: T::CollectionItemId,
+			item_description: Vec<u8>,
+			item_files: Vec<CollectionItemFileParamsOf<T>>,
+			item_token: Option<CollectionItemTokenParams>,
+			restricted_delivery: bool,
+			terms_and_conditions: Vec<TermsAndConditionsElementParamsOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_no_migration_in_progress()?;
+			let who = ensure_signed(origin)?;
+
+			let mut item = Self::build_collection_item(item_description, item_files, item_token, restricted_delivery, terms_and_conditions)?;
+
+			let collection_loc_option = <LocMap<T>>::get(&collection_loc_id);
+			match collection_loc_option {
+				None => Err(Error::<T>::WrongCollectionLoc)?,
+				Some(collection_loc) => {
+					let previous_item = <CollectionItemsMap<T>>::get(&collection_loc_id, &item_id)
+						.ok_or(Error::<T>::CollectionItemNotFound)?;
+					if ! Self::can_add_item(&who, &collection_loc) {
+						Err(Error::<T>::WrongCollectionLoc)?
+					}
+					if previous_item.void_info.is_some() {
+						Err(Error::<T>::CannotMutateVoid)?
+					}
+					if Self::collection_submission_ended(&collection_loc) {
+						Err(Error::<T>::CollectionLimitsReached)?
+					}
+					Self::validate_collection_item(&collection_loc, &item)?;
+					item.replacer_of = previous_item.replacer_of.clone();
+					let actual_weight = T::WeightInfo::update_collection_item(item.files.len() as u32, item.terms_and_conditions.len() as u32)
+						.saturating_add(Self::previous_revision_weight(previous_item.files.len() as u32, previous_item.terms_and_conditions.len() as u32));
+
+					Self::reserve_deposit(&who, &collection_loc_id, item.encoded_size())?;
+					let revision = <CollectionItemRevisionMap<T>>::get(&collection_loc_id, &item_id);
+					<CollectionItemRevisionsMap<T>>::insert((&collection_loc_id, &item_id, revision), previous_item);
+					<CollectionItemRevisionMap<T>>::insert(&collection_loc_id, &item_id, revision + 1);
+					Self::unindex_collection_item_files(&collection_loc_id, &item_id, &previous_item);
+					Self::remove_terms_and_conditions_references(&collection_loc_id, &previous_item);
+					Self::index_collection_item_files(&collection_loc_id, &item_id, &item);
+					Self::add_terms_and_conditions_references(&collection_loc_id, &item);
+					<CollectionItemsMap<T>>::insert(collection_loc_id, item_id, item);
+
+					Self::deposit_event(Event::ItemUpdated(collection_loc_id, item_id, revision + 1));
+					Ok(Some(actual_weight).into())
+				},
+			}
+		}
+
+		/// The weight of unindexing the files and terms and conditions of the previous revision of an updated
+		/// collection item, not included in `WeightInfo::update_collection_item`.
+		pub(crate) fn previous_revision_weight(files: u32, terms_and_conditions: u32) -> Weight {
+			T::DbWeight::get().reads_writes(terms_and_conditions as u64, files.saturating_add(terms_and_conditions) as u64)
+		}
+
+		fn build_collection_item(
+			item_description: Vec<u8>,
+			item_files: Vec<CollectionItemFileParamsOf<T>>,
+			item_token: Option<CollectionItemTokenParams>,
+			restricted_delivery: bool,
+			terms_and_conditions: Vec<TermsAndConditionsElementParamsOf<T>>,
+		) -> Result<CollectionItemOf<T>, Error<T>> {
+			let item_description: BoundedVec<u8, T::MaxCollectionItemDescriptionSize> = item_description.try_into()
+				.map_err(|_| Error::<T>::CollectionItemTooMuchData)?;
+
+			let item_token: Option<CollectionItemTokenOf<T>> = match item_token {
+				None => None,
+				Some(token) => Some(token.try_into().map_err(|_| Error::<T>::CollectionItemTooMuchData)?),
+			};
+
+			let item_files: BoundedVec<CollectionItemFileOf<T>, T::MaxCollectionItemFiles> = try_into_bounded(item_files)
+				.map_err(|_| Error::<T>::CollectionItemTooMuchData)?;
+
+			let terms_and_conditions: BoundedVec<TermsAndConditionsElementOf<T>, T::MaxCollectionItemTermsAndConditions> = try_into_bounded(terms_and_conditions)
+				.map_err(|_| Error::<T>::CollectionItemTooMuchData)?;
+
+			if restricted_delivery && item_token.is_none() {
+				Err(Error::<T>::MissingToken)?
+			}
+
+			if restricted_delivery && item_files.len() == 0 {
+				Err(Error::<T>::MissingFiles)?
+			}
+
+			Ok(CollectionItemOf::<T> {
+				description: item_description,
+				files: item_files,
+				token: item_token,
+				restricted_delivery,
+				terms_and_conditions,
+				void_info: None,
+				replacer_of: None,
+			})
+		}
+
+		fn validate_collection_item(
+			collection_loc: &LegalOfficerCaseOf<T>,
+			item: &CollectionItemOf<T>,
+		) -> Result<(), Error<T>> {
+			if !collection_loc.collection_can_upload && item.files.len() > 0 {
+				Err(Error::<T>::CannotUpload)?
+			}
+			if collection_loc.collection_can_upload {
+				if item.files.len() == 0 {
+					Err(Error::<T>::MustUpload)?
+				} else {
+					let files_hashes: Vec<<T as Config>::Hash> = item.files.iter()
+						.map(|file| file.hash)
+						.collect();
+					if !Self::has_unique_elements(&files_hashes) {
+						Err(Error::<T>::DuplicateFile)?
+					}
+				}
+			}
+
+			for terms_and_conditions_element in item.terms_and_conditions.iter() {
+				if !<LocMap<T>>::contains_key(&terms_and_conditions_element.tc_loc) {
+					Err(Error::<T>::TermsAndConditionsLocNotFound)?
+				} else {
+					let tc_loc = <LocMap<T>>::get(terms_and_conditions_element.tc_loc).unwrap();
+					if tc_loc.void_info.is_some() {
+						Err(Error::<T>::TermsAndConditionsLocVoid)?
+					} else if !tc_loc.closed {
+						Err(Error::<T>::TermsAndConditionsLocNotClosed)?
+					}
+				}
+			}
+			Ok(())
+		}
 	}
 }
@@ -1,20 +1,1041 @@
 use frame_support::codec::{Decode, Encode};
-use frame_support::traits::Get;
+use frame_support::traits::{Get, GetStorageVersion, StorageVersion};
 use frame_support::dispatch::Vec;
 use frame_support::weights::Weight;
 use frame_support::traits::OnRuntimeUpgrade;
+use frame_support::{BoundedVec, Blake2_128Concat, storage_alias};
+use frame_support::storage::StoragePrefixedMap;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec;
 
-use crate::{Config, LegalOfficerCaseOf, pallet, PalletStorageVersion, pallet::StorageVersion};
+use crate::{Config, pallet, Pallet};
+
+pub mod storage_version {
+	use super::*;
+	use frame_support::codec::DecodeAll;
+	use frame_support::storage::unhashed;
+	use super::v10::CollectionItemV9Of;
+
+	#[derive(Encode, Decode, Eq, PartialEq, Debug)]
+	pub enum LegacyStorageVersion {
+		V1,
+		V2MakeLocVoid,
+		V3RequesterEnum,
+		V4ItemSubmitter,
+		V5Collection,
+		V6ItemUpload,
+		V7ItemToken,
+		V8AddSeal,
+		V9TermsAndConditions,
+		V10BoundedStorage,
+		V11AcknowledgeItems,
+		V12CollectionItemVoid,
+		V13LocsByOwner,
+		V14FileHashIndex,
+		V15LinkedFrom,
+		V16ClosedIdentityLocs,
+		V17LocIndexesDoubleMaps,
+		V18SplitLocContent,
+	}
+
+	/// Storage version as recorded before MigrateToFrameStorageVersion. The value was missing on chains which never
+	/// ran a migration, see `legacy_storage_version`.
+	#[storage_alias]
+	pub type PalletStorageVersion<T: Config> = StorageValue<Pallet<T>, LegacyStorageVersion>;
+
+	/// Moves the storage version from the legacy PalletStorageVersion value to FRAME's storage version,
+	/// version N being the Nth variant of LegacyStorageVersion. Must run before any other migration.
+	pub struct MigrateToFrameStorageVersion<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToFrameStorageVersion<T> {
+
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() == StorageVersion::new(0) {
+				let storage_version = legacy_storage_version::<T>();
+				PalletStorageVersion::<T>::kill();
+				storage_version.put::<Pallet<T>>();
+				log::info!("✅ \"MigrateToFrameStorageVersion\" migration successfully executed, now at {:?}", storage_version);
+				T::DbWeight::get().reads_writes(3, 2)
+			} else {
+				log::info!("❎ \"MigrateToFrameStorageVersion\" execution skipped, storage version already set");
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			if Pallet::<T>::on_chain_storage_version() == StorageVersion::new(0) {
+				Ok(legacy_storage_version::<T>().encode())
+			} else {
+				Ok(Vec::new())
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			if !state.is_empty() {
+				let storage_version = StorageVersion::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+				frame_support::ensure!(Pallet::<T>::on_chain_storage_version() >= storage_version, "Storage version was not moved");
+				frame_support::ensure!(!PalletStorageVersion::<T>::exists(), "Legacy storage version was not removed");
+			}
+			Ok(())
+		}
+	}
+
+	fn legacy_storage_version<T: Config>() -> StorageVersion {
+		let legacy_version = PalletStorageVersion::<T>::get().unwrap_or_else(unrecorded_storage_version::<T>);
+		StorageVersion::new(legacy_version as u16 + 1)
+	}
+
+	/// A missing PalletStorageVersion used to mean V8AddSeal, but chains started with V9TermsAndConditions code
+	/// never recorded a version either. V8AddSeal and V9TermsAndConditions only differ by the layout of collection
+	/// items: V9TermsAndConditions is assumed if the first collection item decodes with terms and conditions, so that
+	/// AddTermsAndConditionsToCollectionItem does not run again and wipe them. Without collection items, both
+	/// versions are equivalent.
+	fn unrecorded_storage_version<T: Config>() -> LegacyStorageVersion {
+		let prefix = crate::CollectionItemsMap::<T>::final_prefix();
+		let first_item = frame_support::sp_io::storage::next_key(&prefix)
+			.filter(|key| key.starts_with(&prefix))
+			.and_then(|key| unhashed::get_raw(&key));
+		match first_item {
+			Some(item) if CollectionItemV9Of::<T>::decode_all(&mut &item[..]).is_ok() => LegacyStorageVersion::V9TermsAndConditions,
+			_ => LegacyStorageVersion::V8AddSeal,
+		}
+	}
+}
+
+pub mod multi_block {
+	use super::*;
+	use frame_support::ReversibleStorageHasher;
+	use frame_support::storage::unhashed;
+	use frame_support::weights::RuntimeDbWeight;
+	use crate::*;
+
+	/// Progress of a multi-block migration.
+	#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, Debug, TypeInfo)]
+	pub struct MigrationCursor {
+		/// Index of the map being translated
+		pub map_index: u32,
+		/// Raw storage key of the last translated entry of the map, if any
+		pub last_key: Option<Vec<u8>>,
+	}
+
+	/// Weight of each byte read or written by a multi-block migration, on top of the weight of the storage access.
+	pub const MIGRATION_WEIGHT_PER_BYTE: u64 = 1_000;
+
+	/// The maximum number of bytes read or written by a multi-block migration in a block, bounding the size of the
+	/// storage proof of the block.
+	pub const MAX_MIGRATION_BYTES_PER_BLOCK: u64 = 1024 * 1024;
+
+	/// Weight and storage bytes a multi-block migration may still consume in the current block.
+	pub struct MigrationBudget {
+		db_weight: RuntimeDbWeight,
+		remaining_ref_time: u64,
+		remaining_bytes: u64,
+		consumed_weight: Weight,
+	}
+
+	impl MigrationBudget {
+		pub fn new(db_weight: RuntimeDbWeight, remaining_weight: Weight) -> Self {
+			Self {
+				db_weight,
+				remaining_ref_time: remaining_weight.ref_time(),
+				remaining_bytes: MAX_MIGRATION_BYTES_PER_BLOCK,
+				consumed_weight: Weight::zero(),
+			}
+		}
+
+		/// Consumes the weight of `reads` reads and `writes` writes of `bytes` bytes in total if the budget allows it,
+		/// returns `false` otherwise. Bytes exceeding `MAX_MIGRATION_BYTES_PER_BLOCK` are accepted if no byte was
+		/// consumed yet in the block, so that a large entry does not stall the migration.
+		pub fn try_consume(&mut self, reads: u64, writes: u64, bytes: usize) -> bool {
+			let bytes = bytes as u64;
+			let weight = self.db_weight.reads_writes(reads, writes)
+				.saturating_add(Weight::from_ref_time(MIGRATION_WEIGHT_PER_BYTE.saturating_mul(bytes)));
+			let bytes_fit = bytes <= self.remaining_bytes || self.remaining_bytes == MAX_MIGRATION_BYTES_PER_BLOCK;
+			if weight.ref_time() > self.remaining_ref_time || !bytes_fit {
+				return false;
+			}
+			self.remaining_ref_time -= weight.ref_time();
+			self.remaining_bytes = self.remaining_bytes.saturating_sub(bytes);
+			self.consumed_weight = self.consumed_weight.saturating_add(weight);
+			true
+		}
+
+		/// The weight consumed so far.
+		pub fn consumed_weight(&self) -> Weight {
+			self.consumed_weight
+		}
+	}
+
+	/// A migration translating storage entries over several blocks. Once started by `StartMultiBlockMigration`,
+	/// it is run by `on_idle` and mutating calls are rejected until it completes. The migration upgrades the storage
+	/// version as it progresses.
+	pub trait MultiBlockMigration {
+		/// Name of the migration, used in logs
+		const NAME: &'static str;
+		/// Lowest storage version the migration applies to
+		const EXPECTED_VERSION: StorageVersion;
+		/// Storage version reached once the migration completes
+		const TARGET_VERSION: StorageVersion;
+
+		/// Translates the entries following `cursor` as long as `budget` allows it. Returns the cursor to resume
+		/// from, `None` once the migration completed or stopped.
+		fn translate_entries(cursor: MigrationCursor, budget: &mut MigrationBudget) -> Option<MigrationCursor>;
+
+		/// Returns the item counts to check once the migration completed, see `post_migration`.
+		#[cfg(feature = "try-runtime")]
+		fn pre_migration() -> Vec<u32> {
+			Vec::new()
+		}
+
+		/// Checks storage once the migration completed, given the item counts returned by `pre_migration`.
+		#[cfg(feature = "try-runtime")]
+		fn post_migration(_counts: Vec<u32>) -> Result<(), &'static str> {
+			Ok(())
+		}
+	}
+
+	/// Progress of a step of a multi-block migration, a step upgrading storage by one version.
+	#[derive(PartialEq, Eq, Debug)]
+	pub enum StepProgress {
+		/// The step must be resumed from the cursor
+		InProgress(MigrationCursor),
+		/// All entries were migrated
+		Completed,
+		/// The step cannot be applied, storage is left at the step's version
+		Refused,
+	}
+
+	impl MultiBlockMigration for () {
+		const NAME: &'static str = "None";
+		const EXPECTED_VERSION: StorageVersion = StorageVersion::new(0);
+		const TARGET_VERSION: StorageVersion = StorageVersion::new(0);
+
+		fn translate_entries(_cursor: MigrationCursor, _budget: &mut MigrationBudget) -> Option<MigrationCursor> {
+			None
+		}
+	}
+
+	/// Runs a step visiting `maps` maps, starting from `cursor`. `visit_map` is given the index of the map to visit
+	/// and the raw key of its last visited entry, and returns the raw key to resume from, `None` once the map is done.
+	pub fn run_step<F>(mut cursor: MigrationCursor, maps: u32, budget: &mut MigrationBudget, mut visit_map: F) -> StepProgress
+	where F: FnMut(u32, Option<Vec<u8>>, &mut MigrationBudget) -> Option<Vec<u8>> {
+		while cursor.map_index < maps {
+			match visit_map(cursor.map_index, cursor.last_key, budget) {
+				Some(last_key) => return StepProgress::InProgress(MigrationCursor { map_index: cursor.map_index, last_key: Some(last_key) }),
+				None => cursor = MigrationCursor { map_index: cursor.map_index + 1, last_key: None },
+			}
+		}
+		StepProgress::Completed
+	}
+
+	/// Translates the values of the map with given final prefix following the entry with raw key `last_key`, as long
+	/// as `budget` allows it. Each entry is charged a read and a write of its previous and new encoded values. Values
+	/// which cannot be decoded or are translated to `None` are removed. Returns the raw key of the last translated
+	/// entry, `None` if no entry is left.
+	pub fn translate_values<O: Decode, V: Encode, F: FnMut(O) -> Option<V>>(
+		prefix: &[u8],
+		last_key: Option<Vec<u8>>,
+		budget: &mut MigrationBudget,
+		mut f: F,
+	) -> Option<Vec<u8>> {
+		let mut key = last_key.unwrap_or_else(|| prefix.to_vec());
+		loop {
+			if !budget.try_consume(1, 0, 0) {
+				return Some(key);
+			}
+			let next_key = match frame_support::sp_io::storage::next_key(&key) {
+				Some(next_key) if next_key.starts_with(prefix) => next_key,
+				_ => return None,
+			};
+			let value = unhashed::get_raw(&next_key).unwrap_or_default();
+			let new_value = O::decode(&mut &value[..]).ok().and_then(&mut f).map(|new_value| new_value.encode());
+			let bytes = value.len().saturating_add(new_value.as_ref().map_or(0, |new_value| new_value.len()));
+			if !budget.try_consume(0, 1, bytes) {
+				return Some(key);
+			}
+			match new_value {
+				Some(new_value) => unhashed::put_raw(&next_key, &new_value),
+				None => unhashed::kill(&next_key),
+			}
+			key = next_key;
+		}
+	}
+
+	/// Visits the values of the map with given final prefix following the entry with raw key `last_key`, as long as
+	/// `budget` allows it. Each entry is charged a read of its value plus the accesses returned by `accesses`, then
+	/// given to `f` with its key material (its raw key without the prefix). Values which cannot be decoded are
+	/// skipped. Returns the raw key of the last visited entry, `None` if no entry is left.
+	pub fn visit_values<V: Decode, A: Fn(&V) -> (u64, u64), F: FnMut(&[u8], V)>(
+		prefix: &[u8],
+		last_key: Option<Vec<u8>>,
+		budget: &mut MigrationBudget,
+		accesses: A,
+		mut f: F,
+	) -> Option<Vec<u8>> {
+		let mut key = last_key.unwrap_or_else(|| prefix.to_vec());
+		loop {
+			if !budget.try_consume(1, 0, 0) {
+				return Some(key);
+			}
+			let next_key = match frame_support::sp_io::storage::next_key(&key) {
+				Some(next_key) if next_key.starts_with(prefix) => next_key,
+				_ => return None,
+			};
+			let value = unhashed::get_raw(&next_key).unwrap_or_default();
+			match V::decode(&mut &value[..]) {
+				Ok(decoded) => {
+					let (reads, writes) = accesses(&decoded);
+					if !budget.try_consume(reads, writes, value.len()) {
+						return Some(key);
+					}
+					f(&next_key[prefix.len()..], decoded);
+				},
+				Err(_) => log::error!("❗ cannot decode value with raw key {:?}, skipped", next_key),
+			}
+			key = next_key;
+		}
+	}
+
+	/// Decodes a key hashed with `Blake2_128Concat` at the beginning of `key_material`, which is advanced past it.
+	pub fn decode_key<K: Decode>(key_material: &mut &[u8]) -> Option<K> {
+		let mut key = Blake2_128Concat::reverse(*key_material);
+		let decoded = K::decode(&mut key).ok()?;
+		*key_material = key;
+		Some(decoded)
+	}
+
+	/// Starts `T::MultiBlockMigration` if storage is at a version it applies to.
+	pub struct StartMultiBlockMigration<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for StartMultiBlockMigration<T> {
+
+		fn on_runtime_upgrade() -> Weight {
+			let name = <T::MultiBlockMigration as MultiBlockMigration>::NAME;
+			let expected_version = <T::MultiBlockMigration as MultiBlockMigration>::EXPECTED_VERSION;
+			let storage_version = Pallet::<T>::on_chain_storage_version();
+			if storage_version >= expected_version
+				&& storage_version < <T::MultiBlockMigration as MultiBlockMigration>::TARGET_VERSION
+				&& !OngoingMigration::<T>::exists() {
+				OngoingMigration::<T>::put(MigrationCursor::default());
+				log::info!("✅ {:?} multi-block migration started", name);
+				T::DbWeight::get().reads_writes(2, 1)
+			} else {
+				log::info!("❎ {:?} multi-block migration not started with storage version {:?}", name, storage_version);
+				T::DbWeight::get().reads(2)
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let storage_version = Pallet::<T>::on_chain_storage_version();
+			if storage_version >= <T::MultiBlockMigration as MultiBlockMigration>::EXPECTED_VERSION
+				&& storage_version < <T::MultiBlockMigration as MultiBlockMigration>::TARGET_VERSION
+				&& !OngoingMigration::<T>::exists() {
+				Ok(<T::MultiBlockMigration as MultiBlockMigration>::pre_migration().encode())
+			} else {
+				Ok(Vec::new())
+			}
+		}
+
+		/// Checks that the migration started then, as it would otherwise only complete in later blocks, runs it to
+		/// completion and checks the migrated storage. The migration is rolled back once checked.
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			if state.is_empty() {
+				return Ok(());
+			}
+			let counts: Vec<u32> = Decode::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+			let mut cursor = OngoingMigration::<T>::get().ok_or("Multi-block migration was not started")?;
+			frame_support::storage::with_transaction(|| {
+				loop {
+					let mut budget = MigrationBudget::new(T::DbWeight::get(), Weight::from_ref_time(u64::MAX));
+					match <T::MultiBlockMigration as MultiBlockMigration>::translate_entries(cursor, &mut budget) {
+						Some(next_cursor) => cursor = next_cursor,
+						None => break,
+					}
+				}
+				let result = <T::MultiBlockMigration as MultiBlockMigration>::post_migration(counts)
+					.map_err(sp_runtime::DispatchError::Other);
+				frame_support::storage::TransactionOutcome::Rollback(result)
+			}).map_err(|error| error.into())
+		}
+	}
+}
+
+pub mod v18 {
+	use super::*;
+	use super::multi_block::{decode_key, MigrationBudget, MigrationCursor, MultiBlockMigration, StepProgress};
+	use frame_support::storage::unhashed;
+	use crate::*;
+
+	#[derive(Encode, Decode)]
+	pub struct LegalOfficerCaseV17<T: Config> {
+		pub owner: T::AccountId,
+		pub requester: RequesterOf<T>,
+		pub metadata: BoundedVec<MetadataItemOf<T>, T::MaxLocMetadata>,
+		pub files: BoundedVec<FileOf<T>, T::MaxLocFiles>,
+		pub closed: bool,
+		pub loc_type: LocType,
+		pub links: BoundedVec<LocLinkOf<T>, T::MaxLocLinks>,
+		pub void_info: Option<LocVoidInfo<T::LocId>>,
+		pub replacer_of: Option<T::LocId>,
+		pub collection_last_block_submission: Option<T::BlockNumber>,
+		pub collection_max_size: Option<CollectionSize>,
+		pub collection_can_upload: bool,
+		pub seal: Option<<T as Config>::Hash>,
+	}
+
+	/// LocMap with LOC metadata, files and links inlined, as before V18SplitLocContent.
+	#[storage_alias]
+	pub type LocMap<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, <T as Config>::LocId, LegalOfficerCaseV17<T>>;
+
+	type Step = fn(MigrationCursor, &mut MigrationBudget) -> StepProgress;
+
+	/// Migrates storage from any version between 9 and 16 to version 18, running the steps of versions 10 to 18 in
+	/// turn. Each step runs over as many blocks as needed and upgrades the storage version once completed. The
+	/// migration stops, storage being left at version 9, if some LOCs or collection items exceed the bounds
+	/// configured by the runtime (see `v10::bound_locs_and_collection_items`).
+	pub struct MigrateToV18<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> MultiBlockMigration for MigrateToV18<T> {
+		const NAME: &'static str = "MigrateToV18";
+		const EXPECTED_VERSION: StorageVersion = StorageVersion::new(9);
+		const TARGET_VERSION: StorageVersion = StorageVersion::new(18);
+
+		fn translate_entries(mut cursor: MigrationCursor, budget: &mut MigrationBudget) -> Option<MigrationCursor> {
+			let steps: [(u16, u16, Step); 8] = [
+				(9, 10, super::v10::bound_locs_and_collection_items::<T>),
+				(10, 11, super::v11::acknowledge_existing_items::<T>),
+				(11, 12, super::v12::add_void_info_to_collection_items::<T>),
+				(12, 13, super::v13::index_locs_by_owner::<T>),
+				(13, 14, super::v14::index_file_hashes::<T>),
+				(14, 15, super::v15::index_linked_from::<T>),
+				(15, 16, super::v16::index_closed_identity_locs::<T>),
+				(16, 18, move_loc_indexes_and_split_loc_content::<T>),
+			];
+			loop {
+				if !budget.try_consume(1, 1, 0) {
+					return Some(cursor);
+				}
+				let storage_version = Pallet::<T>::on_chain_storage_version();
+				let (_, target_version, step) = steps.iter()
+					.find(|(from, _, _)| storage_version == StorageVersion::new(*from))?;
+				match step(cursor, budget) {
+					StepProgress::InProgress(next_cursor) => return Some(next_cursor),
+					StepProgress::Completed => {
+						StorageVersion::new(*target_version).put::<Pallet<T>>();
+						log::info!("✅ {:?} upgraded storage to version {:?}", Self::NAME, target_version);
+						cursor = MigrationCursor::default();
+					},
+					StepProgress::Refused => return None,
+				}
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_migration() -> Vec<u32> {
+			vec![
+				crate::LocMap::<T>::iter_keys().count() as u32,
+				crate::CollectionItemsMap::<T>::iter_keys().count() as u32,
+			]
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_migration(counts: Vec<u32>) -> Result<(), &'static str> {
+			frame_support::ensure!(Pallet::<T>::on_chain_storage_version() == Self::TARGET_VERSION, "Migration did not reach its target version");
+			frame_support::ensure!(counts == Self::pre_migration(), "Item counts changed during migration");
+			let locs: Vec<(T::LocId, LegalOfficerCaseOf<T>)> = crate::LocMap::<T>::iter().collect();
+			frame_support::ensure!(locs.len() as u32 == counts[0], "Some LOCs cannot be decoded after migration");
+			frame_support::ensure!(crate::CollectionItemsMap::<T>::iter_values().count() as u32 == counts[1], "Some collection items cannot be decoded after migration");
+			for (loc_id, loc) in locs {
+				frame_support::ensure!(
+					LocMetadataMap::<T>::iter_prefix_values(&loc_id).count() as u32 == loc.metadata_count
+						&& LocFilesMap::<T>::iter_prefix_values(&loc_id).count() as u32 == loc.files_count
+						&& LocLinksMap::<T>::iter_prefix_values(&loc_id).count() as u32 == loc.links_count,
+					"LOC items were not all moved to their own maps"
+				);
+				let next_loc_id = sp_runtime::traits::CheckedAdd::checked_add(&loc_id, &sp_runtime::traits::One::one());
+				frame_support::ensure!(next_loc_id.map_or(true, |next_loc_id| Pallet::<T>::next_loc_id() >= next_loc_id), "NextLocId was not moved past existing LOCs");
+			}
+			Ok(())
+		}
+	}
+
+	/// Moves the legacy lists of requested LOCs (maps 0 and 1, see `v17::move_legacy_loc_indexes`), then moves LOC
+	/// metadata, files and links to their own maps (map 2). All items are kept, in their original order.
+	pub fn move_loc_indexes_and_split_loc_content<T: Config>(cursor: MigrationCursor, budget: &mut MigrationBudget) -> StepProgress {
+		let last_key = if cursor.map_index < 2 {
+			match super::v17::move_legacy_loc_indexes::<T>(cursor, budget) {
+				Some(cursor) => return StepProgress::InProgress(cursor),
+				None => None,
+			}
+		} else {
+			cursor.last_key
+		};
+		match split_loc_content::<T>(last_key, budget) {
+			Some(last_key) => StepProgress::InProgress(MigrationCursor { map_index: 2, last_key: Some(last_key) }),
+			None => StepProgress::Completed,
+		}
+	}
+
+	/// Splits the LOCs following the entry with raw key `last_key` as long as `budget` allows it, and moves NextLocId
+	/// past their IDs. Each LOC is charged a read and a write for the LOC, NextLocId and each of its items, plus twice
+	/// its encoded size. LOCs which cannot be decoded are left unchanged. Returns the raw key of the last split LOC,
+	/// `None` if no LOC is left.
+	fn split_loc_content<T: Config>(last_key: Option<Vec<u8>>, budget: &mut MigrationBudget) -> Option<Vec<u8>> {
+		let prefix = crate::LocMap::<T>::final_prefix();
+		let mut key = last_key.unwrap_or_else(|| prefix.to_vec());
+		loop {
+			if !budget.try_consume(1, 0, 0) {
+				return Some(key);
+			}
+			let next_key = match frame_support::sp_io::storage::next_key(&key) {
+				Some(next_key) if next_key.starts_with(&prefix) => next_key,
+				_ => return None,
+			};
+			let value = unhashed::get_raw(&next_key).unwrap_or_default();
+			match (decode_key::<T::LocId>(&mut &next_key[prefix.len()..]), LegalOfficerCaseV17::<T>::decode(&mut &value[..])) {
+				(Some(loc_id), Ok(loc)) => {
+					let items = loc.metadata.len() + loc.files.len() + loc.links.len();
+					if !budget.try_consume(1, 2 + items as u64, value.len().saturating_mul(2)) {
+						return Some(key);
+					}
+					unhashed::put(&next_key, &split_loc::<T>(&loc_id, loc));
+					Pallet::<T>::advance_next_loc_id(&loc_id);
+				},
+				_ => {
+					log::error!("❗ {:?} cannot decode LOC with raw key {:?}, left unchanged", MigrateToV18::<T>::NAME, next_key);
+				},
+			}
+			key = next_key;
+		}
+	}
+
+	/// Stores the items of given LOC in their own maps and returns the LOC without its items.
+	fn split_loc<T: Config>(loc_id: &T::LocId, loc: LegalOfficerCaseV17<T>) -> LegalOfficerCaseOf<T> {
+		let metadata_count = loc.metadata.len() as u32;
+		for (index, item) in loc.metadata.into_iter().enumerate() {
+			LocMetadataMap::<T>::insert(loc_id, index as u32, item);
+		}
+		let files_count = loc.files.len() as u32;
+		for (index, file) in loc.files.into_iter().enumerate() {
+			LocFilesMap::<T>::insert(loc_id, index as u32, file);
+		}
+		let links_count = loc.links.len() as u32;
+		for (index, link) in loc.links.into_iter().enumerate() {
+			LocLinksMap::<T>::insert(loc_id, index as u32, link);
+		}
+		LegalOfficerCaseOf::<T> {
+			owner: loc.owner,
+			requester: loc.requester,
+			metadata_count,
+			files_count,
+			closed: loc.closed,
+			loc_type: loc.loc_type,
+			links_count,
+			void_info: loc.void_info,
+			replacer_of: loc.replacer_of,
+			collection_last_block_submission: loc.collection_last_block_submission,
+			collection_max_size: loc.collection_max_size,
+			collection_can_upload: loc.collection_can_upload,
+			seal: loc.seal,
+		}
+	}
+}
+
+pub mod v17 {
+	use super::*;
+	use super::multi_block::{MigrationBudget, MigrationCursor};
+	use crate::*;
+
+	/// Moves the legacy lists of requested LOCs by account (map 0) and by logion Identity LOC (map 1) to AccountLocs
+	/// and IdentityLocLocs as long as `budget` allows it. Moved lists are removed so that the cursor only records the
+	/// map being moved. Returns the cursor to resume from, `None` once both legacy maps are empty.
+	pub fn move_legacy_loc_indexes<T: Config>(mut cursor: MigrationCursor, budget: &mut MigrationBudget) -> Option<MigrationCursor> {
+		loop {
+			let moved = match cursor.map_index {
+				0 => move_next_account_locs::<T>(budget),
+				1 => move_next_identity_loc_locs::<T>(budget),
+				_ => return None,
+			};
+			match moved {
+				Some(true) => (),
+				Some(false) => return Some(cursor),
+				None => cursor = MigrationCursor { map_index: cursor.map_index + 1, last_key: None },
+			}
+		}
+	}
+
+	/// Moves the LOC IDs of the first legacy list of AccountLocsMap. Returns `None` if no list is left,
+	/// `Some(false)` if `budget` did not allow to move the whole list.
+	fn move_next_account_locs<T: Config>(budget: &mut MigrationBudget) -> Option<bool> {
+		if !budget.try_consume(1, 0, 0) {
+			return Some(false);
+		}
+		let (account, mut loc_ids) = AccountLocsMap::<T>::iter().next()?;
+		let moved_items = match moved_items(budget, &loc_ids) {
+			Some(moved_items) => moved_items,
+			None => return Some(false),
+		};
+		for loc_id in loc_ids.drain(..moved_items) {
+			AccountLocs::<T>::insert(&account, &loc_id, ());
+		}
+		AccountLocsCount::<T>::mutate(&account, |count| *count = count.saturating_add(moved_items as u32));
+		if loc_ids.is_empty() {
+			AccountLocsMap::<T>::remove(&account);
+			Some(true)
+		} else {
+			AccountLocsMap::<T>::insert(&account, loc_ids);
+			Some(false)
+		}
+	}
+
+	/// Moves the LOC IDs of the first legacy list of IdentityLocLocsMap. Returns `None` if no list is left,
+	/// `Some(false)` if `budget` did not allow to move the whole list.
+	fn move_next_identity_loc_locs<T: Config>(budget: &mut MigrationBudget) -> Option<bool> {
+		if !budget.try_consume(1, 0, 0) {
+			return Some(false);
+		}
+		let (identity_loc_id, mut loc_ids) = IdentityLocLocsMap::<T>::iter().next()?;
+		let moved_items = match moved_items(budget, &loc_ids) {
+			Some(moved_items) => moved_items,
+			None => return Some(false),
+		};
+		for loc_id in loc_ids.drain(..moved_items) {
+			IdentityLocLocs::<T>::insert(&identity_loc_id, &loc_id, ());
+		}
+		IdentityLocLocsCount::<T>::mutate(&identity_loc_id, |count| *count = count.saturating_add(moved_items as u32));
+		if loc_ids.is_empty() {
+			IdentityLocLocsMap::<T>::remove(&identity_loc_id);
+			Some(true)
+		} else {
+			IdentityLocLocsMap::<T>::insert(&identity_loc_id, loc_ids);
+			Some(false)
+		}
+	}
+
+	/// Consumes the update of a legacy list and its count, then a write for each of its LOC IDs as long as `budget`
+	/// allows it. Returns the number of LOC IDs which can be moved, `None` if the list cannot be updated.
+	fn moved_items<LocId: Encode>(budget: &mut MigrationBudget, loc_ids: &Vec<LocId>) -> Option<usize> {
+		if !budget.try_consume(1, 2, 2 * loc_ids.encoded_size()) {
+			return None;
+		}
+		let mut moved_items = 0;
+		while moved_items < loc_ids.len() && budget.try_consume(0, 1, 0) {
+			moved_items += 1;
+		}
+		Some(moved_items)
+	}
+}
+
+pub mod v16 {
+	use super::*;
+	use super::multi_block::{decode_key, run_step, visit_values, MigrationBudget, MigrationCursor, StepProgress};
+	use super::v18::LegalOfficerCaseV17;
+	use crate::*;
+
+	/// Indexes closed and non-void Polkadot Identity LOCs by requester and owner.
+	pub fn index_closed_identity_locs<T: Config>(cursor: MigrationCursor, budget: &mut MigrationBudget) -> StepProgress {
+		run_step(cursor, 1, budget, |_, last_key, budget| visit_values(
+			&LocMap::<T>::final_prefix(),
+			last_key,
+			budget,
+			|_| (0, 2),
+			|mut key: &[u8], loc: LegalOfficerCaseV17<T>| {
+				if loc.closed && loc.void_info.is_none() && loc.loc_type == LocType::Identity {
+					if let (Some(loc_id), Requester::Account(requester)) = (decode_key::<T::LocId>(&mut key), loc.requester) {
+						ClosedIdentityLocs::<T>::insert(&requester, &loc.owner, loc_id);
+						AllClosedIdentityLocs::<T>::insert((requester, loc.owner), loc_id, ());
+					}
+				}
+			},
+		))
+	}
+}
+
+pub mod v15 {
+	use super::*;
+	use super::multi_block::{decode_key, run_step, visit_values, MigrationBudget, MigrationCursor, StepProgress};
+	use super::v18::LegalOfficerCaseV17;
+	use crate::*;
+
+	/// Indexes the LOCs linking to a LOC (map 0) or using it as terms and conditions (map 1).
+	pub fn index_linked_from<T: Config>(cursor: MigrationCursor, budget: &mut MigrationBudget) -> StepProgress {
+		run_step(cursor, 2, budget, |map_index, last_key, budget| match map_index {
+			0 => visit_values(
+				&LocMap::<T>::final_prefix(),
+				last_key,
+				budget,
+				|loc: &LegalOfficerCaseV17<T>| (loc.links.len() as u64, loc.links.len() as u64),
+				|mut key: &[u8], loc: LegalOfficerCaseV17<T>| {
+					if let Some(loc_id) = decode_key::<T::LocId>(&mut key) {
+						for link in loc.links.iter() {
+							Pallet::<T>::add_reference(&link.id, &loc_id);
+						}
+					}
+				},
+			),
+			_ => visit_values(
+				&CollectionItemsMap::<T>::final_prefix(),
+				last_key,
+				budget,
+				|item: &CollectionItemOf<T>| (item.terms_and_conditions.len() as u64, item.terms_and_conditions.len() as u64),
+				|mut key: &[u8], item: CollectionItemOf<T>| {
+					if let Some(loc_id) = decode_key::<T::LocId>(&mut key) {
+						Pallet::<T>::add_terms_and_conditions_references(&loc_id, &item);
+					}
+				},
+			),
+		})
+	}
+}
+
+pub mod v14 {
+	use super::*;
+	use super::multi_block::{decode_key, run_step, visit_values, MigrationBudget, MigrationCursor, StepProgress};
+	use super::v18::LegalOfficerCaseV17;
+	use crate::*;
+
+	/// Indexes the hashes of LOC files (map 0) and collection item files (map 1).
+	pub fn index_file_hashes<T: Config>(cursor: MigrationCursor, budget: &mut MigrationBudget) -> StepProgress {
+		run_step(cursor, 2, budget, |map_index, last_key, budget| match map_index {
+			0 => visit_values(
+				&LocMap::<T>::final_prefix(),
+				last_key,
+				budget,
+				|loc: &LegalOfficerCaseV17<T>| (0, loc.files.len() as u64),
+				|mut key: &[u8], loc: LegalOfficerCaseV17<T>| {
+					if let Some(loc_id) = decode_key::<T::LocId>(&mut key) {
+						for file in loc.files.iter() {
+							Pallet::<T>::index_file_hash(&file.hash, &loc_id, None);
+						}
+					}
+				},
+			),
+			_ => visit_values(
+				&CollectionItemsMap::<T>::final_prefix(),
+				last_key,
+				budget,
+				|item: &CollectionItemOf<T>| (0, item.files.len() as u64),
+				|mut key: &[u8], item: CollectionItemOf<T>| {
+					if let (Some(loc_id), Some(item_id)) = (decode_key::<T::LocId>(&mut key), decode_key::<T::CollectionItemId>(&mut key)) {
+						Pallet::<T>::index_collection_item_files(&loc_id, &item_id, &item);
+					}
+				},
+			),
+		})
+	}
+}
+
+pub mod v13 {
+	use super::*;
+	use super::multi_block::{decode_key, run_step, visit_values, MigrationBudget, MigrationCursor, StepProgress};
+	use super::v18::LegalOfficerCaseV17;
+	use crate::*;
+
+	/// Indexes LOCs by owner.
+	pub fn index_locs_by_owner<T: Config>(cursor: MigrationCursor, budget: &mut MigrationBudget) -> StepProgress {
+		run_step(cursor, 1, budget, |_, last_key, budget| visit_values(
+			&LocMap::<T>::final_prefix(),
+			last_key,
+			budget,
+			|_| (0, 1),
+			|mut key: &[u8], loc: LegalOfficerCaseV17<T>| {
+				if let Some(loc_id) = decode_key::<T::LocId>(&mut key) {
+					LocsByOwner::<T>::insert(loc.owner, loc_id, ());
+				}
+			},
+		))
+	}
+}
+
+pub mod v12 {
+	use super::*;
+	use super::multi_block::{run_step, translate_values, MigrationBudget, MigrationCursor, StepProgress};
+	use crate::*;
+
+	#[derive(Encode, Decode)]
+	pub struct CollectionItemV11<T: Config> {
+		pub description: BoundedVec<u8, T::MaxCollectionItemDescriptionSize>,
+		pub files: BoundedVec<CollectionItemFileOf<T>, T::MaxCollectionItemFiles>,
+		pub token: Option<CollectionItemTokenOf<T>>,
+		pub restricted_delivery: bool,
+		pub terms_and_conditions: BoundedVec<TermsAndConditionsElementOf<T>, T::MaxCollectionItemTermsAndConditions>,
+	}
+
+	/// Adds void information to collection items (map 0) and their revisions (map 1).
+	pub fn add_void_info_to_collection_items<T: Config>(cursor: MigrationCursor, budget: &mut MigrationBudget) -> StepProgress {
+		run_step(cursor, 2, budget, |map_index, last_key, budget| match map_index {
+			0 => translate_values(&CollectionItemsMap::<T>::final_prefix(), last_key, budget, |item: CollectionItemV11<T>| Some(to_collection_item::<T>(item))),
+			_ => translate_values(&CollectionItemRevisionsMap::<T>::final_prefix(), last_key, budget, |item: CollectionItemV11<T>| Some(to_collection_item::<T>(item))),
+		})
+	}
+
+	fn to_collection_item<T: Config>(item: CollectionItemV11<T>) -> CollectionItemOf<T> {
+		CollectionItemOf::<T> {
+			description: item.description,
+			files: item.files,
+			token: item.token,
+			restricted_delivery: item.restricted_delivery,
+			terms_and_conditions: item.terms_and_conditions,
+			void_info: None,
+			replacer_of: None,
+		}
+	}
+}
+
+pub mod v11 {
+	use super::*;
+	use super::multi_block::{run_step, translate_values, MigrationBudget, MigrationCursor, StepProgress};
+	use super::v18::LegalOfficerCaseV17;
+	use crate::*;
+
+	#[derive(Encode, Decode)]
+	pub struct MetadataItemV10<T: Config> {
+		pub name: BoundedVec<u8, T::MaxMetadataItemNameSize>,
+		pub value: BoundedVec<u8, T::MaxMetadataItemValueSize>,
+		pub submitter: T::AccountId,
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct FileV10<T: Config> {
+		pub hash: <T as Config>::Hash,
+		pub nature: BoundedVec<u8, T::MaxFileNatureSize>,
+		pub submitter: T::AccountId,
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct LegalOfficerCaseV10<T: Config> {
+		pub owner: T::AccountId,
+		pub requester: RequesterOf<T>,
+		pub metadata: BoundedVec<MetadataItemV10<T>, T::MaxLocMetadata>,
+		pub files: BoundedVec<FileV10<T>, T::MaxLocFiles>,
+		pub closed: bool,
+		pub loc_type: LocType,
+		pub links: BoundedVec<LocLinkOf<T>, T::MaxLocLinks>,
+		pub void_info: Option<LocVoidInfo<T::LocId>>,
+		pub replacer_of: Option<T::LocId>,
+		pub collection_last_block_submission: Option<T::BlockNumber>,
+		pub collection_max_size: Option<CollectionSize>,
+		pub collection_can_upload: bool,
+		pub seal: Option<<T as Config>::Hash>,
+	}
+
+	/// Acknowledges the metadata items and files of existing LOCs.
+	pub fn acknowledge_existing_items<T: Config>(cursor: MigrationCursor, budget: &mut MigrationBudget) -> StepProgress {
+		run_step(cursor, 1, budget, |_, last_key, budget| translate_values(
+			&LocMap::<T>::final_prefix(),
+			last_key,
+			budget,
+			|loc: LegalOfficerCaseV10<T>| Some(LegalOfficerCaseV17::<T> {
+				owner: loc.owner,
+				requester: loc.requester,
+				metadata: map_bounded(loc.metadata, |item| MetadataItem {
+					name: item.name,
+					value: item.value,
+					submitter: item.submitter,
+					acknowledged: true,
+				}),
+				files: map_bounded(loc.files, |file| File {
+					hash: file.hash,
+					nature: file.nature,
+					submitter: file.submitter,
+					acknowledged: true,
+				}),
+				closed: loc.closed,
+				loc_type: loc.loc_type,
+				links: loc.links,
+				void_info: loc.void_info,
+				replacer_of: loc.replacer_of,
+				collection_last_block_submission: loc.collection_last_block_submission,
+				collection_max_size: loc.collection_max_size,
+				collection_can_upload: loc.collection_can_upload,
+				seal: loc.seal,
+			}),
+		))
+	}
+}
+
+pub mod v10 {
+	use super::*;
+	use super::multi_block::{run_step, translate_values, visit_values, MigrationBudget, MigrationCursor, StepProgress};
+	use super::v11::{FileV10, LegalOfficerCaseV10, MetadataItemV10};
+	use super::v12::CollectionItemV11;
+	use crate::*;
+
+	#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	pub struct MetadataItemV9<AccountId> {
+		pub name: Vec<u8>,
+		pub value: Vec<u8>,
+		pub submitter: AccountId,
+	}
+
+	#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	pub struct LocLinkV9<LocId> {
+		pub id: LocId,
+		pub nature: Vec<u8>,
+	}
+
+	#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	pub struct FileV9<Hash, AccountId> {
+		pub hash: Hash,
+		pub nature: Vec<u8>,
+		pub submitter: AccountId,
+	}
+
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	pub struct LegalOfficerCaseV9<AccountId, Hash, LocId, BlockNumber> {
+		pub owner: AccountId,
+		pub requester: Requester<AccountId, LocId>,
+		pub metadata: Vec<MetadataItemV9<AccountId>>,
+		pub files: Vec<FileV9<Hash, AccountId>>,
+		pub closed: bool,
+		pub loc_type: LocType,
+		pub links: Vec<LocLinkV9<LocId>>,
+		pub void_info: Option<LocVoidInfo<LocId>>,
+		pub replacer_of: Option<LocId>,
+		pub collection_last_block_submission: Option<BlockNumber>,
+		pub collection_max_size: Option<CollectionSize>,
+		pub collection_can_upload: bool,
+		pub seal: Option<Hash>,
+	}
+
+	pub type LegalOfficerCaseV9Of<T> = LegalOfficerCaseV9<<T as frame_system::Config>::AccountId, <T as pallet::Config>::Hash, <T as pallet::Config>::LocId, <T as frame_system::Config>::BlockNumber>;
+
+	#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	pub struct TermsAndConditionsElementV9<LocId> {
+		pub tc_type: Vec<u8>,
+		pub tc_loc: LocId,
+		pub details: Vec<u8>,
+	}
+
+	#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	pub struct CollectionItemFileV9<Hash> {
+		pub name: Vec<u8>,
+		pub content_type: Vec<u8>,
+		pub size: u32,
+		pub hash: Hash,
+	}
+
+	#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	pub struct CollectionItemTokenV9 {
+		pub token_type: Vec<u8>,
+		pub token_id: Vec<u8>,
+	}
+
+	#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	pub struct CollectionItemV9<Hash, LocId> {
+		pub description: Vec<u8>,
+		pub files: Vec<CollectionItemFileV9<Hash>>,
+		pub token: Option<CollectionItemTokenV9>,
+		pub restricted_delivery: bool,
+		pub terms_and_conditions: Vec<TermsAndConditionsElementV9<LocId>>,
+	}
+
+	pub type CollectionItemV9Of<T> = CollectionItemV9<<T as pallet::Config>::Hash, <T as pallet::Config>::LocId>;
+
+	/// Bounds LOCs and collection items. All entries are first checked (maps 0 and 1): existing data must fit in the
+	/// bounds configured by the runtime, the step is refused otherwise so that the bounds can be increased before
+	/// migrating again. Entries are then translated (maps 2 and 3).
+	pub fn bound_locs_and_collection_items<T: Config>(cursor: MigrationCursor, budget: &mut MigrationBudget) -> StepProgress {
+		let mut exceeding_entry_found = false;
+		let progress = run_step(cursor, 4, budget, |map_index, last_key, budget| {
+			if exceeding_entry_found {
+				return None;
+			}
+			match map_index {
+				0 => visit_values(&LocMap::<T>::final_prefix(), last_key, budget, |_| (0, 0), |_, loc: LegalOfficerCaseV9Of<T>| {
+					exceeding_entry_found |= bound_loc::<T>(loc).is_none();
+				}),
+				1 => visit_values(&CollectionItemsMap::<T>::final_prefix(), last_key, budget, |_| (0, 0), |_, item: CollectionItemV9Of<T>| {
+					exceeding_entry_found |= bound_collection_item::<T>(item).is_none();
+				}),
+				2 => translate_values(&LocMap::<T>::final_prefix(), last_key, budget, bound_loc::<T>),
+				_ => translate_values(&CollectionItemsMap::<T>::final_prefix(), last_key, budget, bound_collection_item::<T>),
+			}
+		});
+		if exceeding_entry_found {
+			log::error!("❗ Bounding LOCs and collection items refused, some of them exceed the configured bounds");
+			StepProgress::Refused
+		} else {
+			progress
+		}
+	}
+
+	/// Returns given LOC with bounded content, `None` if its content exceeds the configured bounds.
+	pub fn bound_loc<T: Config>(loc: LegalOfficerCaseV9Of<T>) -> Option<LegalOfficerCaseV10<T>> {
+		Some(LegalOfficerCaseV10::<T> {
+			owner: loc.owner,
+			requester: loc.requester,
+			metadata: bound(loc.metadata.into_iter().map(|item| Some(MetadataItemV10 {
+				name: bound(item.name)?,
+				value: bound(item.value)?,
+				submitter: item.submitter,
+			})).collect::<Option<Vec<_>>>()?)?,
+			files: bound(loc.files.into_iter().map(|file| Some(FileV10 {
+				hash: file.hash,
+				nature: bound(file.nature)?,
+				submitter: file.submitter,
+			})).collect::<Option<Vec<_>>>()?)?,
+			closed: loc.closed,
+			loc_type: loc.loc_type,
+			links: bound(loc.links.into_iter().map(|link| Some(LocLink {
+				id: link.id,
+				nature: bound(link.nature)?,
+			})).collect::<Option<Vec<_>>>()?)?,
+			void_info: loc.void_info,
+			replacer_of: loc.replacer_of,
+			collection_last_block_submission: loc.collection_last_block_submission,
+			collection_max_size: loc.collection_max_size,
+			collection_can_upload: loc.collection_can_upload,
+			seal: loc.seal,
+		})
+	}
+
+	/// Returns given collection item with bounded content, `None` if its content exceeds the configured bounds.
+	pub fn bound_collection_item<T: Config>(item: CollectionItemV9Of<T>) -> Option<CollectionItemV11<T>> {
+		Some(CollectionItemV11::<T> {
+			description: bound(item.description)?,
+			files: bound(item.files.into_iter().map(|file| Some(CollectionItemFile {
+				name: bound(file.name)?,
+				content_type: bound(file.content_type)?,
+				size: file.size,
+				hash: file.hash,
+			})).collect::<Option<Vec<_>>>()?)?,
+			token: match item.token {
+				Some(token) => Some(CollectionItemToken {
+					token_type: bound(token.token_type)?,
+					token_id: bound(token.token_id)?,
+				}),
+				None => None,
+			},
+			restricted_delivery: item.restricted_delivery,
+			terms_and_conditions: bound(item.terms_and_conditions.into_iter().map(|tc| Some(TermsAndConditionsElement {
+				tc_type: bound(tc.tc_type)?,
+				tc_loc: tc.tc_loc,
+				details: bound(tc.details)?,
+			})).collect::<Option<Vec<_>>>()?)?,
+		})
+	}
+
+	/// Bounds a vector, `None` if it exceeds the bound.
+	fn bound<I, S: Get<u32>>(items: Vec<I>) -> Option<BoundedVec<I, S>> {
+		BoundedVec::try_from(items).ok()
+	}
+}
 
 pub mod v9 {
 	use super::*;
-	use crate::{CollectionItemFile, CollectionItemsMap, CollectionItemOf, CollectionItemToken};
+	use crate::Pallet;
+	use super::v10::{CollectionItemFileV9, CollectionItemTokenV9, CollectionItemV9Of};
+
+	#[storage_alias]
+	type CollectionItemsMap<T: Config> = StorageDoubleMap<Pallet<T>, Blake2_128Concat, <T as Config>::LocId, Blake2_128Concat, <T as Config>::CollectionItemId, CollectionItemV9Of<T>>;
 
 	#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
 	struct CollectionItemV8<Hash> {
 		description: Vec<u8>,
-		files: Vec<CollectionItemFile<Hash>>,
-		token: Option<CollectionItemToken>,
+		files: Vec<CollectionItemFileV9<Hash>>,
+		token: Option<CollectionItemTokenV9>,
 		restricted_delivery: bool,
 	}
 
@@ -25,12 +1046,14 @@ pub mod v9 {
 
 		fn on_runtime_upgrade() -> Weight {
 			super::do_storage_upgrade::<T, _>(
-				StorageVersion::V8AddSeal,
-				StorageVersion::V9TermsAndConditions,
+				StorageVersion::new(8),
+				StorageVersion::new(9),
 				"AddTermsAndConditionsToCollectionItem",
 				|| {
+					let mut accesses = DbAccesses::default();
 					CollectionItemsMap::<T>::translate(|_loc_id: T::LocId, _item_id: T::CollectionItemId, item: CollectionItemV8Of<T>| {
-						let new_item = CollectionItemOf::<T> {
+						accesses.add(1, 1);
+						let new_item = CollectionItemV9Of::<T> {
 							description: item.description.clone(),
 							files: item.files.clone(),
 							token: item.token.clone(),
@@ -39,25 +1062,45 @@ pub mod v9 {
 						};
 						Some(new_item)
 					});
+					accesses
 				}
 			)
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			super::do_pre_upgrade::<T, _>(StorageVersion::new(8), || vec![CollectionItemsMap::<T>::iter_keys().count() as u32])
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			super::do_post_upgrade::<T, _, _>(
+				state,
+				StorageVersion::new(9),
+				|| vec![CollectionItemsMap::<T>::iter_keys().count() as u32],
+				|| vec![CollectionItemsMap::<T>::iter_values().count() as u32],
+			)
+		}
 	}
 }
 
 pub mod v8 {
 	use super::*;
 	use crate::*;
+	use super::v10::{FileV9, LegalOfficerCaseV9Of, LocLinkV9, MetadataItemV9};
+
+	#[storage_alias]
+	type LocMap<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, <T as Config>::LocId, LegalOfficerCaseV9Of<T>>;
 
 	#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
 	pub struct LegalOfficerCaseV7<AccountId, Hash, LocId, BlockNumber> {
 		owner: AccountId,
 		requester: Requester<AccountId, LocId>,
-		metadata: Vec<MetadataItem<AccountId>>,
-		files: Vec<File<Hash, AccountId>>,
+		metadata: Vec<MetadataItemV9<AccountId>>,
+		files: Vec<FileV9<Hash, AccountId>>,
 		closed: bool,
 		loc_type: LocType,
-		links: Vec<LocLink<LocId>>,
+		links: Vec<LocLinkV9<LocId>>,
 		void_info: Option<LocVoidInfo<LocId>>,
 		replacer_of: Option<LocId>,
 		collection_last_block_submission: Option<BlockNumber>,
@@ -72,12 +1115,14 @@ pub mod v8 {
 
 		fn on_runtime_upgrade() -> Weight {
 			super::do_storage_upgrade::<T, _>(
-				StorageVersion::V7ItemToken, 
-				StorageVersion::V8AddSeal, 
+				StorageVersion::new(7),
+				StorageVersion::new(8),
 				"AddSealToLoc",
 				|| {
+					let mut accesses = DbAccesses::default();
 					LocMap::<T>::translate_values(|loc: LegalOfficerCaseOfV7<T>| {
-						Some(LegalOfficerCaseOf::<T> {
+						accesses.add(1, 1);
+						Some(LegalOfficerCaseV9Of::<T> {
 							owner: loc.owner,
 							requester: loc.requester,
 							metadata: loc.metadata,
@@ -92,22 +1137,59 @@ pub mod v8 {
 							collection_can_upload: loc.collection_can_upload,
 							seal: Option::None,
 						})
-					})
+					});
+					accesses
 				}
 			)
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			super::do_pre_upgrade::<T, _>(StorageVersion::new(7), || vec![LocMap::<T>::iter_keys().count() as u32])
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			super::do_post_upgrade::<T, _, _>(
+				state,
+				StorageVersion::new(8),
+				|| vec![LocMap::<T>::iter_keys().count() as u32],
+				|| vec![LocMap::<T>::iter_values().count() as u32],
+			)
+		}
 	}
 }
 
+/// Maps the elements of a bounded vector, the bound being unchanged.
+fn map_bounded<I, O, S: Get<u32>, F: FnMut(I) -> O>(items: BoundedVec<I, S>, f: F) -> BoundedVec<O, S> {
+	BoundedVec::try_from(items.into_iter().map(f).collect::<Vec<O>>()).map_err(|_| ()).expect("bound is unchanged; qed")
+}
+
+/// Storage reads and writes performed by a migration.
+#[derive(Default)]
+struct DbAccesses {
+	reads: u64,
+	writes: u64,
+}
+
+impl DbAccesses {
+	fn add(&mut self, reads: u64, writes: u64) {
+		self.reads = self.reads.saturating_add(reads);
+		self.writes = self.writes.saturating_add(writes);
+	}
+}
+
+/// Runs `migration` if storage is at `expected_version`. The returned weight is computed from the storage accesses
+/// counted by the migration, plus the read and the write of the storage version.
 fn do_storage_upgrade<T: Config, F>(expected_version: StorageVersion, target_version: StorageVersion, migration_name: &str, migration: F) -> Weight
-where F: FnOnce() -> () {
-	let storage_version = PalletStorageVersion::<T>::get();
+where F: FnOnce() -> DbAccesses {
+	let storage_version = Pallet::<T>::on_chain_storage_version();
 	if storage_version == expected_version {
-		migration();
+		let accesses = migration();
 
-		PalletStorageVersion::<T>::set(target_version);
-		log::info!("✅ {:?} migration successfully executed", migration_name);
-		T::BlockWeights::get().max_block
+		target_version.put::<Pallet<T>>();
+		log::info!("✅ {:?} migration successfully executed ({:?} reads, {:?} writes)", migration_name, accesses.reads, accesses.writes);
+		T::DbWeight::get().reads_writes(accesses.reads.saturating_add(1), accesses.writes.saturating_add(1))
 	} else {
 		if storage_version != target_version {
 			log::warn!("❗ {:?} cannot run migration with storage version {:?} (expected {:?})", migration_name, storage_version, expected_version);
@@ -117,3 +1199,36 @@ where F: FnOnce() -> () {
 		T::DbWeight::get().reads(1)
 	}
 }
+
+/// Records the item counts returned by `counts` if the migration is about to run.
+#[cfg(feature = "try-runtime")]
+fn do_pre_upgrade<T: Config, F>(expected_version: StorageVersion, counts: F) -> Result<Vec<u8>, &'static str>
+where F: FnOnce() -> Vec<u32> {
+	if Pallet::<T>::on_chain_storage_version() == expected_version {
+		Ok(counts().encode())
+	} else {
+		Ok(Vec::new())
+	}
+}
+
+/// Checks that the storage version was upgraded and, if the migration ran, that `counts` still returns the item
+/// counts recorded by `do_pre_upgrade`. `decoded_counts` counts the items which decode with the layout written by
+/// the migration, it is checked only if no later migration changed the layout.
+#[cfg(feature = "try-runtime")]
+fn do_post_upgrade<T: Config, F, D>(state: Vec<u8>, target_version: StorageVersion, counts: F, decoded_counts: D) -> Result<(), &'static str>
+where
+	F: FnOnce() -> Vec<u32>,
+	D: FnOnce() -> Vec<u32>,
+{
+	let storage_version = Pallet::<T>::on_chain_storage_version();
+	frame_support::ensure!(storage_version >= target_version, "Storage version was not upgraded");
+	if state.is_empty() {
+		return Ok(());
+	}
+	let expected_counts: Vec<u32> = Decode::decode(&mut &state[..]).map_err(|_| "Invalid pre-upgrade state")?;
+	frame_support::ensure!(counts() == expected_counts, "Item counts changed during migration");
+	if storage_version == target_version {
+		frame_support::ensure!(decoded_counts() == expected_counts, "Some items cannot be decoded after migration");
+	}
+	Ok(())
+}
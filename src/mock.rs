@@ -1,11 +1,12 @@
 use crate::{self as pallet_loc, RequesterOf};
+use crate::migrations::v18::MigrateToV18;
 use sp_core::hash::H256;
-use frame_support::{parameter_types, traits::EnsureOrigin};
+use frame_support::{parameter_types, traits::{Currency, EnsureOrigin, GenesisBuild, OnUnbalanced}};
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup}, testing::Header,
+	traits::{BlakeTwo256, IdentityLookup}, testing::Header, Perbill,
 };
 use frame_system as system;
-use system::ensure_signed;
+use system::{ensure_signed, EnsureRoot};
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -17,7 +18,8 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		LogionLoc: pallet_loc::{Pallet, Call, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		LogionLoc: pallet_loc::{Pallet, Call, Storage, Config<T>, Event<T>},
 	}
 );
 
@@ -44,7 +46,7 @@ impl system::Config for Test {
 	type BlockHashCount = BlockHashCount;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -53,11 +55,29 @@ impl system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
 pub const LOC_OWNER1: u64 = 1;
 pub const LOC_OWNER2: u64 = 2;
 pub const LOC_REQUESTER_ID: u64 = 3;
 pub const LOC_REQUESTER: RequesterOf<Test> = RequesterOf::<Test>::Account(LOC_REQUESTER_ID);
 pub const LOGION_IDENTITY_LOC_ID: u32 = 4;
+pub const TREASURY_ACCOUNT_ID: u64 = 5;
+pub const INITIAL_BALANCE: u64 = 1_000_000;
 
 pub struct LoAuthorityListMock;
 impl EnsureOrigin<RuntimeOrigin> for LoAuthorityListMock {
@@ -79,13 +99,37 @@ impl EnsureOrigin<RuntimeOrigin> for LoAuthorityListMock {
 }
 
 parameter_types! {
-	pub const MaxMetadataItemNameSize: usize = 40;
-	pub const MaxMetadataItemValueSize: usize = 4096;
-	pub const MaxFileNatureSize: usize = 255;
-	pub const MaxLinkNatureSize: usize = 255;
-	pub const MaxCollectionItemDescriptionSize: usize = 4096;
-	pub const MaxCollectionItemTokenIdSize: usize = 255;
-	pub const MaxCollectionItemTokenTypeSize: usize = 255;
+	pub const MaxMetadataItemNameSize: u32 = 40;
+	pub const MaxMetadataItemValueSize: u32 = 4096;
+	pub const MaxFileNatureSize: u32 = 255;
+	pub const MaxLinkNatureSize: u32 = 255;
+	pub const MaxLocMetadata: u32 = 50;
+	pub const MaxLocFiles: u32 = 50;
+	pub const MaxLocLinks: u32 = 50;
+	pub const MaxCollectionItemDescriptionSize: u32 = 4096;
+	pub const MaxCollectionItemTokenIdSize: u32 = 255;
+	pub const MaxCollectionItemTokenTypeSize: u32 = 255;
+	pub const MaxCollectionItemFiles: u32 = 10;
+	pub const MaxCollectionItemFileNameSize: u32 = 255;
+	pub const MaxCollectionItemFileContentTypeSize: u32 = 255;
+	pub const MaxCollectionItemTermsAndConditions: u32 = 10;
+	pub const MaxTermsAndConditionsTypeSize: u32 = 255;
+	pub const MaxTermsAndConditionsDetailsSize: u32 = 4096;
+	pub const MaxItemsPerBatch: u32 = 10;
+	pub const DepositBase: u64 = 100;
+	pub const DepositPerByte: u64 = 1;
+	pub const TransactionLocFee: u64 = 2_000;
+	pub const IdentityLocFee: u64 = 1_000;
+	pub const CollectionLocFee: u64 = 5_000;
+	pub const CollectionItemFee: u64 = 100;
+	pub const TreasuryShare: Perbill = Perbill::from_percent(20);
+}
+
+pub struct TreasuryMock;
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Test>> for TreasuryMock {
+	fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Test>) {
+		Balances::resolve_creating(&TREASURY_ACCOUNT_ID, amount);
+	}
 }
 
 impl pallet_loc::Config for Test {
@@ -93,25 +137,63 @@ impl pallet_loc::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Hash = H256;
 	type CreateOrigin = LoAuthorityListMock;
+	type ForceOrigin = EnsureRoot<u64>;
 	type MaxMetadataItemNameSize = MaxMetadataItemNameSize;
 	type MaxMetadataItemValueSize = MaxMetadataItemValueSize;
 	type MaxFileNatureSize = MaxFileNatureSize;
 	type MaxLinkNatureSize = MaxLinkNatureSize;
+	type MaxLocMetadata = MaxLocMetadata;
+	type MaxLocFiles = MaxLocFiles;
+	type MaxLocLinks = MaxLocLinks;
 	type CollectionItemId = H256;
 	type MaxCollectionItemDescriptionSize = MaxCollectionItemDescriptionSize;
 	type MaxCollectionItemTokenIdSize = MaxCollectionItemTokenIdSize;
 	type MaxCollectionItemTokenTypeSize = MaxCollectionItemTokenTypeSize;
+	type MaxCollectionItemFiles = MaxCollectionItemFiles;
+	type MaxCollectionItemFileNameSize = MaxCollectionItemFileNameSize;
+	type MaxCollectionItemFileContentTypeSize = MaxCollectionItemFileContentTypeSize;
+	type MaxCollectionItemTermsAndConditions = MaxCollectionItemTermsAndConditions;
+	type MaxTermsAndConditionsTypeSize = MaxTermsAndConditionsTypeSize;
+	type MaxTermsAndConditionsDetailsSize = MaxTermsAndConditionsDetailsSize;
+	type MaxItemsPerBatch = MaxItemsPerBatch;
+	type Currency = Balances;
+	type DepositBase = DepositBase;
+	type DepositPerByte = DepositPerByte;
+	type TransactionLocFee = TransactionLocFee;
+	type IdentityLocFee = IdentityLocFee;
+	type CollectionLocFee = CollectionLocFee;
+	type CollectionItemFee = CollectionItemFee;
+	type TreasuryShare = TreasuryShare;
+	type FeeDestination = TreasuryMock;
 	type WeightInfo = ();
+	type MultiBlockMigration = MigrateToV18<Test>;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	build_storage(Default::default()).into()
+}
+
+pub fn new_test_ext_with_genesis(loc_genesis: pallet_loc::GenesisConfig<Test>) -> sp_io::TestExternalities {
+	build_storage(loc_genesis).into()
 }
 
 pub fn new_test_ext_at_block(block_number: u64) -> sp_io::TestExternalities {
-	let t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let t = build_storage(Default::default());
 	let mut ext = sp_io::TestExternalities::new(t);
 	ext.execute_with(|| System::set_block_number(block_number));
 	ext
 }
+
+fn build_storage(loc_genesis: pallet_loc::GenesisConfig<Test>) -> sp_runtime::Storage {
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![
+			(LOC_OWNER1, INITIAL_BALANCE),
+			(LOC_OWNER2, INITIAL_BALANCE),
+			(LOC_REQUESTER_ID, INITIAL_BALANCE),
+		],
+	}.assimilate_storage(&mut t).unwrap();
+	loc_genesis.assimilate_storage(&mut t).unwrap();
+	t
+}
@@ -1,11 +1,13 @@
-use frame_support::{assert_err, assert_ok};
+use frame_support::{assert_err, assert_ok, BoundedVec, traits::{Currency, Get, GetStorageVersion, Hooks, StorageVersion}, weights::Weight};
 use frame_support::error::BadOrigin;
+use sp_core::H256;
 use sp_runtime::traits::BlakeTwo256;
 use sp_runtime::traits::Hash;
 
+use codec::Encode;
 use logion_shared::LocQuery;
 
-use crate::{File, LegalOfficerCase, LocLink, LocType, MetadataItem, CollectionItem, CollectionItemFile, CollectionItemToken, mock::*, TermsAndConditionsElement};
+use crate::{CollectionItemParams, CollectionItemVoidInfo, FileParams, GenesisConfig, GenesisLoc, LocVoidInfo, Requester, RequesterOf, FileOf, LegalOfficerCaseOf, LocLinkParams, LocLinkOf, LocType, MetadataItemParams, MetadataItemOf, CollectionItemOf, CollectionItemFileParams, CollectionItemTokenParams, mock::*, TermsAndConditionsElementParams, TermsAndConditionsElementOf};
 use crate::Error;
 
 const LOC_ID: u32 = 0;
@@ -17,14 +19,14 @@ const ADDITIONAL_TC_LOC_ID: u32 = 3;
 fn it_creates_loc() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
-		assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase::<<Test as frame_system::Config>::AccountId, <Test as crate::Config>::Hash, <Test as crate::Config>::LocId, <Test as frame_system::Config>::BlockNumber> {
+		assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCaseOf::<Test> {
 			owner: LOC_OWNER1,
 			requester: LOC_REQUESTER,
-			metadata: vec![],
-			files: vec![],
+			metadata_count: 0,
+			files_count: 0,
 			closed: false,
 			loc_type: LocType::Transaction,
-			links: vec![],
+			links_count: 0,
 			void_info: None,
 			replacer_of: None,
 			collection_last_block_submission: Option::None,
@@ -133,14 +135,15 @@ fn it_fails_replacing_with_wrongly_typed_loc() {
 fn it_adds_metadata_when_submitter_is_owner() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
-		let metadata = MetadataItem {
+		let metadata = MetadataItemParams {
 			name: vec![1, 2, 3],
 			value: vec![4, 5, 6],
 			submitter: LOC_OWNER1,
 		};
 		assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
-		let loc = LogionLoc::loc(LOC_ID).unwrap();
-		assert_eq!(loc.metadata[0], metadata);
+		let expected_metadata: MetadataItemOf<Test> = metadata.try_into().unwrap();
+		assert_eq!(LogionLoc::get_loc_metadata(LOC_ID), vec![expected_metadata]);
+		assert_eq!(LogionLoc::loc(LOC_ID).unwrap().metadata_count, 1);
 	});
 }
 
@@ -148,14 +151,15 @@ fn it_adds_metadata_when_submitter_is_owner() {
 fn it_adds_metadata_when_submitter_is_requester() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
-		let metadata = MetadataItem {
+		let metadata = MetadataItemParams {
 			name: vec![1, 2, 3],
 			value: vec![4, 5, 6],
 			submitter: LOC_REQUESTER_ID,
 		};
 		assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
-		let loc = LogionLoc::loc(LOC_ID).unwrap();
-		assert_eq!(loc.metadata[0], metadata);
+		let expected_metadata: MetadataItemOf<Test> = metadata.try_into().unwrap();
+		assert_eq!(LogionLoc::get_loc_metadata(LOC_ID), vec![expected_metadata]);
+		assert_eq!(LogionLoc::loc(LOC_ID).unwrap().metadata_count, 1);
 	});
 }
 
@@ -163,7 +167,7 @@ fn it_adds_metadata_when_submitter_is_requester() {
 fn it_fails_adding_metadata_for_unauthorized_caller() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
-		let metadata = MetadataItem {
+		let metadata = MetadataItemParams {
 			name: vec![1, 2, 3],
 			value: vec![4, 5, 6],
 			submitter: LOC_OWNER1,
@@ -176,7 +180,7 @@ fn it_fails_adding_metadata_for_unauthorized_caller() {
 fn it_fails_adding_metadata_when_closed() {
 	new_test_ext().execute_with(|| {
 		create_closed_loc();
-		let metadata = MetadataItem {
+		let metadata = MetadataItemParams {
 			name: vec![1, 2, 3],
 			value: vec![4, 5, 6],
 			submitter: LOC_OWNER1,
@@ -189,7 +193,7 @@ fn it_fails_adding_metadata_when_closed() {
 fn it_fails_adding_metadata_on_polkadot_transaction_loc_when_submitter_is_invalid() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
-		let metadata = MetadataItem {
+		let metadata = MetadataItemParams {
 			name: vec![1, 2, 3],
 			value: vec![4, 5, 6],
 			submitter: LOC_OWNER2,
@@ -202,7 +206,7 @@ fn it_fails_adding_metadata_on_polkadot_transaction_loc_when_submitter_is_invali
 fn it_fails_adding_metadata_on_logion_identity_loc_for_when_submitter_is_invalid() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-		let metadata = MetadataItem {
+		let metadata = MetadataItemParams {
 			name: vec![1, 2, 3],
 			value: vec![4, 5, 6],
 			submitter: LOC_OWNER2,
@@ -211,6 +215,40 @@ fn it_fails_adding_metadata_on_logion_identity_loc_for_when_submitter_is_invalid
 	});
 }
 
+#[test]
+fn it_fails_adding_metadata_when_name_too_large() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let metadata = MetadataItemParams {
+			name: vec![0; 41],
+			value: vec![4, 5, 6],
+			submitter: LOC_OWNER1,
+		};
+		assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()), Error::<Test>::MetadataItemInvalid);
+	});
+}
+
+#[test]
+fn it_fails_adding_metadata_when_too_many_items() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		for i in 0..MaxLocMetadata::get() {
+			let metadata = MetadataItemParams {
+				name: vec![i as u8],
+				value: vec![4, 5, 6],
+				submitter: LOC_OWNER1,
+			};
+			assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata));
+		}
+		let metadata = MetadataItemParams {
+			name: vec![1, 2, 3],
+			value: vec![4, 5, 6],
+			submitter: LOC_OWNER1,
+		};
+		assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata), Error::<Test>::TooManyMetadataItems);
+	});
+}
+
 fn create_closed_loc() {
 	assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
 	assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
@@ -220,14 +258,15 @@ fn create_closed_loc() {
 fn it_adds_file_when_submitter_is_owner() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
-		let file = File {
+		let file = FileParams {
 			hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
 			nature: "test-file-nature".as_bytes().to_vec(),
 			submitter: LOC_OWNER1,
 		};
 		assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()));
-		let loc = LogionLoc::loc(LOC_ID).unwrap();
-		assert_eq!(loc.files[0], file);
+		let expected_file: FileOf<Test> = file.try_into().unwrap();
+		assert_eq!(LogionLoc::get_loc_files(LOC_ID), vec![expected_file]);
+		assert_eq!(LogionLoc::loc(LOC_ID).unwrap().files_count, 1);
 	});
 }
 
@@ -235,14 +274,15 @@ fn it_adds_file_when_submitter_is_owner() {
 fn it_adds_file_when_submitter_is_requester() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
-		let file = File {
+		let file = FileParams {
 			hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
 			nature: "test-file-nature".as_bytes().to_vec(),
 			submitter: LOC_REQUESTER_ID,
 		};
 		assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()));
-		let loc = LogionLoc::loc(LOC_ID).unwrap();
-		assert_eq!(loc.files[0], file);
+		let expected_file: FileOf<Test> = file.try_into().unwrap();
+		assert_eq!(LogionLoc::get_loc_files(LOC_ID), vec![expected_file]);
+		assert_eq!(LogionLoc::loc(LOC_ID).unwrap().files_count, 1);
 	});
 }
 
@@ -250,7 +290,7 @@ fn it_adds_file_when_submitter_is_requester() {
 fn it_fails_adding_file_for_unauthorized_caller() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
-		let file = File {
+		let file = FileParams {
 			hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
 			nature: "test-file-nature".as_bytes().to_vec(),
 			submitter: LOC_OWNER1,
@@ -263,7 +303,7 @@ fn it_fails_adding_file_for_unauthorized_caller() {
 fn it_fails_adding_file_when_closed() {
 	new_test_ext().execute_with(|| {
 		create_closed_loc();
-		let file = File {
+		let file = FileParams {
 			hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
 			nature: "test-file-nature".as_bytes().to_vec(),
 			submitter: LOC_OWNER1,
@@ -276,7 +316,7 @@ fn it_fails_adding_file_when_closed() {
 fn it_fails_adding_file_on_polkadot_transaction_loc_when_submitter_is_invalid() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
-		let file = File {
+		let file = FileParams {
 			hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
 			nature: "test-file-nature".as_bytes().to_vec(),
 			submitter: LOC_OWNER2,
@@ -289,7 +329,7 @@ fn it_fails_adding_file_on_polkadot_transaction_loc_when_submitter_is_invalid()
 fn it_fails_adding_file_on_logion_identity_loc_when_submitter_is_invalid() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-		let file = File {
+		let file = FileParams {
 			hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
 			nature: "test-file-nature".as_bytes().to_vec(),
 			submitter: LOC_OWNER2,
@@ -303,13 +343,14 @@ fn it_adds_link() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
 		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOC_REQUESTER_ID));
-		let link = LocLink {
+		let link = LocLinkParams {
 			id: OTHER_LOC_ID,
 			nature: "test-link-nature".as_bytes().to_vec()
 		};
 		assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link.clone()));
-		let loc = LogionLoc::loc(LOC_ID).unwrap();
-		assert_eq!(loc.links[0], link);
+		let expected_link: LocLinkOf<Test> = link.try_into().unwrap();
+		assert_eq!(LogionLoc::get_loc_links(LOC_ID), vec![expected_link]);
+		assert_eq!(LogionLoc::loc(LOC_ID).unwrap().links_count, 1);
 	});
 }
 
@@ -318,7 +359,7 @@ fn it_fails_adding_link_for_unauthorized_caller() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
 		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOC_REQUESTER_ID));
-		let link = LocLink {
+		let link = LocLinkParams {
 			id: OTHER_LOC_ID,
 			nature: "test-link-nature".as_bytes().to_vec()
 		};
@@ -331,7 +372,7 @@ fn it_fails_adding_link_when_closed() {
 	new_test_ext().execute_with(|| {
 		create_closed_loc();
 		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOC_REQUESTER_ID));
-		let link = LocLink {
+		let link = LocLinkParams {
 			id: OTHER_LOC_ID,
 			nature: "test-link-nature".as_bytes().to_vec()
 		};
@@ -343,7 +384,7 @@ fn it_fails_adding_link_when_closed() {
 fn it_fails_adding_wrong_link() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
-		let link = LocLink {
+		let link = LocLinkParams {
 			id: OTHER_LOC_ID,
 			nature: "test-link-nature".as_bytes().to_vec()
 		};
@@ -362,6 +403,21 @@ fn it_closes_loc() {
 	});
 }
 
+#[test]
+fn it_refunds_close_weight_of_missing_items() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let metadata = MetadataItemParams {
+			name: vec![1, 2, 3],
+			value: vec![4, 5, 6],
+			submitter: LOC_OWNER1,
+		};
+		assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata));
+		let post_info = LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as crate::weights::WeightInfo>::close(1)));
+	});
+}
+
 #[test]
 fn it_fails_closing_loc_for_unauthorized_caller() {
 	new_test_ext().execute_with(|| {
@@ -383,10 +439,28 @@ fn it_links_locs_to_account() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
 		assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOC_REQUESTER_ID));
-		assert!(LogionLoc::account_locs(LOC_REQUESTER_ID).is_some());
-		assert!(LogionLoc::account_locs(LOC_REQUESTER_ID).unwrap().len() == 2);
-		assert_eq!(LogionLoc::account_locs(LOC_REQUESTER_ID).unwrap()[0], LOC_ID);
-		assert_eq!(LogionLoc::account_locs(LOC_REQUESTER_ID).unwrap()[1], OTHER_LOC_ID);
+		assert_eq!(LogionLoc::get_account_locs_count(LOC_REQUESTER_ID), 2);
+		let mut account_locs = LogionLoc::get_account_locs(LOC_REQUESTER_ID, None, 10);
+		account_locs.sort();
+		assert_eq!(account_locs, vec![LOC_ID, OTHER_LOC_ID]);
+	});
+}
+
+#[test]
+fn it_queries_locs_and_collection_items() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID, Option::None, Option::Some(10), false));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+		assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, "item-description".as_bytes().to_vec(), vec![], Option::None, false));
+
+		assert_eq!(LogionLoc::get_loc(LOC_ID), LogionLoc::loc(LOC_ID));
+		assert!(LogionLoc::get_loc(OTHER_LOC_ID).is_none());
+		assert_eq!(LogionLoc::get_collection_item(LOC_ID, collection_item_id), LogionLoc::collection_items(LOC_ID, collection_item_id));
+		assert_eq!(LogionLoc::get_account_locs(LOC_REQUESTER_ID, None, 10), vec![LOC_ID]);
+		assert!(LogionLoc::get_account_locs(LOC_OWNER1, None, 10).is_empty());
+		assert!(LogionLoc::get_identity_loc_locs(LOGION_IDENTITY_LOC_ID, None, 10).is_empty());
+		assert_eq!(LogionLoc::get_collection_size(LOC_ID), Some(1));
 	});
 }
 
@@ -417,7 +491,7 @@ fn it_creates_logion_identity_loc() {
 		assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
 
 		assert!(LogionLoc::loc(LOGION_IDENTITY_LOC_ID).is_some());
-		assert!(LogionLoc::identity_loc_locs(LOGION_IDENTITY_LOC_ID).is_none());
+		assert_eq!(LogionLoc::get_identity_loc_locs_count(LOGION_IDENTITY_LOC_ID), 0);
 	});
 }
 
@@ -432,10 +506,10 @@ fn it_creates_and_links_logion_locs_to_identity_loc() {
 
 		assert!(LogionLoc::loc(LOC_ID).is_some());
 		assert!(LogionLoc::loc(OTHER_LOC_ID).is_some());
-		assert!(LogionLoc::identity_loc_locs(LOGION_IDENTITY_LOC_ID).is_some());
-		assert!(LogionLoc::identity_loc_locs(LOGION_IDENTITY_LOC_ID).unwrap().len() == 2);
-		assert_eq!(LogionLoc::identity_loc_locs(LOGION_IDENTITY_LOC_ID).unwrap()[0], LOC_ID);
-		assert_eq!(LogionLoc::identity_loc_locs(LOGION_IDENTITY_LOC_ID).unwrap()[1], OTHER_LOC_ID);
+		assert_eq!(LogionLoc::get_identity_loc_locs_count(LOGION_IDENTITY_LOC_ID), 2);
+		let mut identity_loc_locs = LogionLoc::get_identity_loc_locs(LOGION_IDENTITY_LOC_ID, None, 10);
+		identity_loc_locs.sort();
+		assert_eq!(identity_loc_locs, vec![LOC_ID, OTHER_LOC_ID]);
 	});
 }
 
@@ -492,14 +566,14 @@ fn it_fails_creating_logion_loc_with_closed_void_logion_identity_loc() {
 fn it_creates_collection_loc() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID, Option::None, Option::Some(10), false));
-		assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase::<<Test as frame_system::Config>::AccountId, <Test as crate::Config>::Hash, <Test as crate::Config>::LocId, <Test as frame_system::Config>::BlockNumber> {
+		assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCaseOf::<Test> {
 			owner: LOC_OWNER1,
 			requester: LOC_REQUESTER,
-			metadata: vec![],
-			files: vec![],
+			metadata_count: 0,
+			files_count: 0,
 			closed: false,
 			loc_type: LocType::Collection,
-			links: vec![],
+			links_count: 0,
 			void_info: None,
 			replacer_of: None,
 			collection_last_block_submission: Option::None,
@@ -536,12 +610,14 @@ fn it_adds_item_to_closed_collection_loc() {
 		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
 		let collection_item_description = "item-description".as_bytes().to_vec();
 		assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], Option::None, false));
-		assert_eq!(LogionLoc::collection_items(LOC_ID, collection_item_id), Some(CollectionItem {
-			description: collection_item_description,
-			files: vec![],
+		assert_eq!(LogionLoc::collection_items(LOC_ID, collection_item_id), Some(CollectionItemOf::<Test> {
+			description: collection_item_description.try_into().unwrap(),
+			files: BoundedVec::default(),
 			token: Option::None,
 			restricted_delivery: false,
-			terms_and_conditions: vec![],
+			terms_and_conditions: BoundedVec::default(),
+			void_info: None,
+			replacer_of: None,
 		}));
 		assert_eq!(LogionLoc::collection_size(LOC_ID), Some(1));
 	});
@@ -556,7 +632,7 @@ fn it_fails_to_item_with_terms_and_conditions_when_non_existent_tc_loc() {
 		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
 		let collection_item_description = "item-description".as_bytes().to_vec();
 		let terms_and_conditions_details = "ITEM-A, ITEM-B".as_bytes().to_vec();
-		let terms_and_conditions = vec![TermsAndConditionsElement {
+		let terms_and_conditions = vec![TermsAndConditionsElementParams {
 			tc_type: "Logion".as_bytes().to_vec(),
 			tc_loc: LOGION_CLASSIFICATION_LOC_ID,
 			details: terms_and_conditions_details.clone()
@@ -575,7 +651,7 @@ fn it_fails_to_item_with_terms_and_conditions_when_open_tc_loc() {
 		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
 		let collection_item_description = "item-description".as_bytes().to_vec();
 		let terms_and_conditions_details = "ITEM-A, ITEM-B".as_bytes().to_vec();
-		let terms_and_conditions = vec![TermsAndConditionsElement {
+		let terms_and_conditions = vec![TermsAndConditionsElementParams {
 			tc_type: "Logion".as_bytes().to_vec(),
 			tc_loc: LOGION_CLASSIFICATION_LOC_ID,
 			details: terms_and_conditions_details.clone()
@@ -595,7 +671,7 @@ fn it_fails_to_item_with_terms_and_conditions_when_void_tc_loc() {
 		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
 		let collection_item_description = "item-description".as_bytes().to_vec();
 		let terms_and_conditions_details = "ITEM-A, ITEM-B".as_bytes().to_vec();
-		let terms_and_conditions = vec![TermsAndConditionsElement {
+		let terms_and_conditions = vec![TermsAndConditionsElementParams {
 			tc_type: "Logion".as_bytes().to_vec(),
 			tc_loc: LOGION_CLASSIFICATION_LOC_ID,
 			details: terms_and_conditions_details.clone()
@@ -616,26 +692,33 @@ fn it_adds_item_with_terms_and_conditions_to_closed_collection_loc() {
 
 		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
 		let collection_item_description = "item-description".as_bytes().to_vec();
-		let tc1 = TermsAndConditionsElement {
+		let tc1 = TermsAndConditionsElementParams {
 			tc_type: "Logion".as_bytes().to_vec(),
 			tc_loc: LOGION_CLASSIFICATION_LOC_ID,
 			details: "ITEM-A, ITEM-B".as_bytes().to_vec().clone()
 		};
-		let tc2 = TermsAndConditionsElement {
+		let tc2 = TermsAndConditionsElementParams {
 			tc_type: "Specific".as_bytes().to_vec(),
 			tc_loc: ADDITIONAL_TC_LOC_ID,
 			details: "Some more details".as_bytes().to_vec().clone()
 		};
 		let terms_and_conditions = vec![tc1, tc2];
 		assert_ok!(LogionLoc::add_collection_item_with_terms_and_conditions(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], Option::None, false, terms_and_conditions.clone()));
-		assert_eq!(LogionLoc::collection_items(LOC_ID, collection_item_id), Some(CollectionItem {
-			description: collection_item_description,
-			files: vec![],
+		let expected_terms_and_conditions: Vec<TermsAndConditionsElementOf<Test>> = terms_and_conditions.into_iter()
+			.map(|tc| tc.try_into().unwrap())
+			.collect();
+		assert_eq!(LogionLoc::collection_items(LOC_ID, collection_item_id), Some(CollectionItemOf::<Test> {
+			description: collection_item_description.try_into().unwrap(),
+			files: BoundedVec::default(),
 			token: None,
 			restricted_delivery: false,
-			terms_and_conditions: terms_and_conditions.clone(),
+			terms_and_conditions: expected_terms_and_conditions.try_into().unwrap(),
+			void_info: None,
+			replacer_of: None,
 		}));
 		assert_eq!(LogionLoc::collection_size(LOC_ID), Some(1));
+		assert_eq!(LogionLoc::get_linked_from(LOGION_CLASSIFICATION_LOC_ID), vec![LOC_ID]);
+		assert_eq!(LogionLoc::get_linked_from(ADDITIONAL_TC_LOC_ID), vec![LOC_ID]);
 	});
 }
 
@@ -711,7 +794,7 @@ fn it_fails_adding_item_if_files_attached_but_upload_not_enabled() {
 
 		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
 		let collection_item_description = "item-description".as_bytes().to_vec();
-		let collection_item_files = vec![CollectionItemFile {
+		let collection_item_files = vec![CollectionItemFileParams {
 			name: "picture.png".as_bytes().to_vec(),
 			content_type: "image/png".as_bytes().to_vec(),
 			hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
@@ -741,7 +824,7 @@ fn it_adds_item_with_one_file_attached() {
 
 		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
 		let collection_item_description = "item-description".as_bytes().to_vec();
-		let collection_item_files = vec![CollectionItemFile {
+		let collection_item_files = vec![CollectionItemFileParams {
 			name: "picture.png".as_bytes().to_vec(),
 			content_type: "image/png".as_bytes().to_vec(),
 			hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
@@ -759,13 +842,13 @@ fn it_adds_item_with_token() {
 
 		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
 		let collection_item_description = "item-description".as_bytes().to_vec();
-		let collection_item_files = vec![CollectionItemFile {
+		let collection_item_files = vec![CollectionItemFileParams {
 			name: "picture.png".as_bytes().to_vec(),
 			content_type: "image/png".as_bytes().to_vec(),
 			hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
 			size: 123456,
 		}];
-		let collection_item_token = CollectionItemToken {
+		let collection_item_token = CollectionItemTokenParams {
 			token_type: "ethereum_erc721".as_bytes().to_vec(),
 			token_id: "{\"contract\":\"0x765df6da33c1ec1f83be42db171d7ee334a46df5\",\"token\":\"4391\"}".as_bytes().to_vec(),
 		};
@@ -781,13 +864,13 @@ fn it_fails_adding_item_with_too_large_token_type() {
 
 		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
 		let collection_item_description = "item-description".as_bytes().to_vec();
-		let collection_item_files = vec![CollectionItemFile {
+		let collection_item_files = vec![CollectionItemFileParams {
 			name: "picture.png".as_bytes().to_vec(),
 			content_type: "image/png".as_bytes().to_vec(),
 			hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
 			size: 123456,
 		}];
-		let collection_item_token = CollectionItemToken {
+		let collection_item_token = CollectionItemTokenParams {
 			token_type: vec![0; 256],
 			token_id: "{\"contract\":\"0x765df6da33c1ec1f83be42db171d7ee334a46df5\",\"token\":\"4391\"}".as_bytes().to_vec(),
 		};
@@ -803,13 +886,13 @@ fn it_fails_adding_item_with_too_large_token_id() {
 
 		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
 		let collection_item_description = "item-description".as_bytes().to_vec();
-		let collection_item_files = vec![CollectionItemFile {
+		let collection_item_files = vec![CollectionItemFileParams {
 			name: "picture.png".as_bytes().to_vec(),
 			content_type: "image/png".as_bytes().to_vec(),
 			hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
 			size: 123456,
 		}];
-		let collection_item_token = CollectionItemToken {
+		let collection_item_token = CollectionItemTokenParams {
 			token_type: "ethereum_erc721".as_bytes().to_vec(),
 			token_id: vec![0; 256],
 		};
@@ -825,7 +908,7 @@ fn it_fails_adding_item_with_missing_token() {
 
 		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
 		let collection_item_description = "item-description".as_bytes().to_vec();
-		let collection_item_files = vec![CollectionItemFile {
+		let collection_item_files = vec![CollectionItemFileParams {
 			name: "picture.png".as_bytes().to_vec(),
 			content_type: "image/png".as_bytes().to_vec(),
 			hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
@@ -844,7 +927,7 @@ fn it_fails_adding_item_with_missing_files() {
 		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
 		let collection_item_description = "item-description".as_bytes().to_vec();
 		let collection_item_files = vec![];
-		let collection_item_token = CollectionItemToken {
+		let collection_item_token = CollectionItemTokenParams {
 			token_type: "ethereum_erc721".as_bytes().to_vec(),
 			token_id: "{\"contract\":\"0x765df6da33c1ec1f83be42db171d7ee334a46df5\",\"token\":\"4391\"}".as_bytes().to_vec(),
 		};
@@ -861,13 +944,13 @@ fn it_adds_item_with_two_files_attached() {
 		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
 		let collection_item_description = "item-description".as_bytes().to_vec();
 		let collection_item_files = vec![
-			CollectionItemFile {
+			CollectionItemFileParams {
 				name: "picture.png".as_bytes().to_vec(),
 				content_type: "image/png".as_bytes().to_vec(),
 				hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
 				size: 123456,
 			},
-			CollectionItemFile {
+			CollectionItemFileParams {
 				name: "doc.pdf".as_bytes().to_vec(),
 				content_type: "application/pdf".as_bytes().to_vec(),
 				hash: BlakeTwo256::hash_of(&"some other content".as_bytes().to_vec()),
@@ -888,13 +971,13 @@ fn it_fails_to_add_item_with_duplicate_hash() {
 		let collection_item_description = "item-description".as_bytes().to_vec();
 		let same_hash = BlakeTwo256::hash_of(&"file content".as_bytes().to_vec());
 		let collection_item_files = vec![
-			CollectionItemFile {
+			CollectionItemFileParams {
 				name: "picture.png".as_bytes().to_vec(),
 				content_type: "image/png".as_bytes().to_vec(),
 				hash: same_hash,
 				size: 123456,
 			},
-			CollectionItemFile {
+			CollectionItemFileParams {
 				name: "doc.pdf".as_bytes().to_vec(),
 				content_type: "application/pdf".as_bytes().to_vec(),
 				hash: same_hash,
@@ -917,3 +1000,1604 @@ fn it_closes_and_seals_loc() {
 		assert_eq!(loc.seal.unwrap(), seal);
 	});
 }
+
+#[test]
+fn it_reserves_deposit_on_loc_creation_and_item_addition() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let loc_deposit = LogionLoc::deposit_for(LogionLoc::loc(LOC_ID).unwrap().encoded_size());
+		assert_eq!(Balances::reserved_balance(LOC_OWNER1), loc_deposit);
+
+		let metadata = MetadataItemParams {
+			name: vec![1, 2, 3],
+			value: vec![4, 5, 6],
+			submitter: LOC_OWNER1,
+		};
+		assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
+		let expected_metadata: MetadataItemOf<Test> = metadata.try_into().unwrap();
+		let total_deposit = loc_deposit + LogionLoc::deposit_for(expected_metadata.encoded_size());
+		assert_eq!(Balances::reserved_balance(LOC_OWNER1), total_deposit);
+		assert_eq!(LogionLoc::deposits(LOC_ID, LOC_OWNER1), total_deposit);
+	});
+}
+
+#[test]
+fn it_reserves_collection_item_deposit_from_requester() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID, Option::None, Option::Some(10), false));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+		let collection_item_description = "item-description".as_bytes().to_vec();
+		assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], Option::None, false));
+
+		let item = LogionLoc::collection_items(LOC_ID, collection_item_id).unwrap();
+		let item_deposit = LogionLoc::deposit_for(item.encoded_size());
+		assert_eq!(Balances::reserved_balance(LOC_REQUESTER_ID), item_deposit);
+		assert_eq!(LogionLoc::deposits(LOC_ID, LOC_REQUESTER_ID), item_deposit);
+	});
+}
+
+#[test]
+fn it_fails_creating_loc_when_deposit_cannot_be_reserved() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&LOC_OWNER1, DepositBase::get());
+		assert_err!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID), Error::<Test>::InsufficientFunds);
+		assert!(LogionLoc::loc(LOC_ID).is_none());
+	});
+}
+
+#[test]
+fn it_charges_loc_fee_to_requester() {
+	new_test_ext_at_block(1).execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let deposit = LogionLoc::deposits(LOC_ID, LOC_OWNER1);
+		assert_eq!(Balances::free_balance(LOC_OWNER1), INITIAL_BALANCE - deposit);
+		assert_eq!(LogionLoc::loc_fees(LOC_ID), Some(TransactionLocFee::get()));
+
+		assert_err!(LogionLoc::pay_loc_fee(RuntimeOrigin::signed(LOC_OWNER2), LOC_ID), Error::<Test>::Unauthorized);
+		assert_ok!(LogionLoc::pay_loc_fee(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID));
+
+		let treasury_fee = TreasuryShare::get() * TransactionLocFee::get();
+		let legal_officer_fee = TransactionLocFee::get() - treasury_fee;
+		assert_eq!(Balances::free_balance(LOC_REQUESTER_ID), INITIAL_BALANCE - TransactionLocFee::get());
+		assert_eq!(Balances::free_balance(LOC_OWNER1), INITIAL_BALANCE - deposit + legal_officer_fee);
+		assert_eq!(Balances::free_balance(TREASURY_ACCOUNT_ID), treasury_fee);
+		System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::FeesCharged(LOC_ID, LOC_REQUESTER_ID, legal_officer_fee, treasury_fee)));
+		assert_eq!(LogionLoc::loc_fees(LOC_ID), None);
+		assert_err!(LogionLoc::pay_loc_fee(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID), Error::<Test>::NoLocFeeDue);
+	});
+}
+
+#[test]
+fn it_charges_collection_item_fee_to_requester() {
+	new_test_ext_at_block(1).execute_with(|| {
+		assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID, Option::None, Option::Some(10), false));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+		let owner_balance = Balances::free_balance(LOC_OWNER1);
+		let requester_balance = Balances::free_balance(LOC_REQUESTER_ID);
+
+		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+		let collection_item_description = "item-description".as_bytes().to_vec();
+		assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], Option::None, false));
+
+		let treasury_fee = TreasuryShare::get() * CollectionItemFee::get();
+		let legal_officer_fee = CollectionItemFee::get() - treasury_fee;
+		let item_deposit = LogionLoc::deposits(LOC_ID, LOC_REQUESTER_ID);
+		assert_eq!(Balances::free_balance(LOC_REQUESTER_ID), requester_balance - item_deposit - CollectionItemFee::get());
+		assert_eq!(Balances::free_balance(LOC_OWNER1), owner_balance + legal_officer_fee);
+		System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::ItemAdded(LOC_ID, collection_item_id)));
+		System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::FeesCharged(LOC_ID, LOC_REQUESTER_ID, legal_officer_fee, treasury_fee)));
+	});
+}
+
+#[test]
+fn it_fails_paying_loc_fee_when_requester_cannot_pay() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		Balances::make_free_balance_be(&LOC_REQUESTER_ID, 300);
+		assert_err!(LogionLoc::pay_loc_fee(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID), Error::<Test>::InsufficientFunds);
+		assert_eq!(LogionLoc::loc_fees(LOC_ID), Some(TransactionLocFee::get()));
+	});
+}
+
+#[test]
+fn it_deletes_metadata_and_releases_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let loc_deposit = LogionLoc::deposits(LOC_ID, LOC_OWNER1);
+		let metadata = MetadataItemParams {
+			name: vec![1, 2, 3],
+			value: vec![4, 5, 6],
+			submitter: LOC_OWNER1,
+		};
+		assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
+		assert_ok!(LogionLoc::delete_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name));
+		assert!(LogionLoc::get_loc_metadata(LOC_ID).is_empty());
+		assert_eq!(LogionLoc::loc(LOC_ID).unwrap().metadata_count, 0);
+		assert_eq!(LogionLoc::deposits(LOC_ID, LOC_OWNER1), loc_deposit);
+		assert_eq!(Balances::reserved_balance(LOC_OWNER1), loc_deposit);
+	});
+}
+
+#[test]
+fn it_fails_deleting_missing_metadata() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_err!(LogionLoc::delete_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, vec![1, 2, 3]), Error::<Test>::MetadataItemNotFound);
+	});
+}
+
+#[test]
+fn it_fails_deleting_metadata_for_unauthorized_caller() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let metadata = MetadataItemParams {
+			name: vec![1, 2, 3],
+			value: vec![4, 5, 6],
+			submitter: LOC_OWNER1,
+		};
+		assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
+		assert_err!(LogionLoc::delete_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.name), Error::<Test>::Unauthorized);
+	});
+}
+
+#[test]
+fn it_fails_deleting_metadata_when_closed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let metadata = MetadataItemParams {
+			name: vec![1, 2, 3],
+			value: vec![4, 5, 6],
+			submitter: LOC_OWNER1,
+		};
+		assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+		assert_err!(LogionLoc::delete_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name), Error::<Test>::CannotMutate);
+	});
+}
+
+#[test]
+fn it_deletes_file() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let file = FileParams {
+			hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
+			nature: "test-file-nature".as_bytes().to_vec(),
+			submitter: LOC_OWNER1,
+		};
+		assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()));
+		assert_ok!(LogionLoc::delete_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash));
+		assert!(LogionLoc::get_loc_files(LOC_ID).is_empty());
+		assert_eq!(LogionLoc::loc(LOC_ID).unwrap().files_count, 0);
+		assert_err!(LogionLoc::delete_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash), Error::<Test>::FileNotFound);
+	});
+}
+
+#[test]
+fn it_deletes_link() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOC_REQUESTER_ID));
+		let link = LocLinkParams {
+			id: OTHER_LOC_ID,
+			nature: "test-link-nature".as_bytes().to_vec()
+		};
+		assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link.clone()));
+		assert_ok!(LogionLoc::delete_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, OTHER_LOC_ID));
+		assert!(LogionLoc::get_loc_links(LOC_ID).is_empty());
+		assert_eq!(LogionLoc::loc(LOC_ID).unwrap().links_count, 0);
+		assert_err!(LogionLoc::delete_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, OTHER_LOC_ID), Error::<Test>::LinkNotFound);
+	});
+}
+
+#[test]
+fn it_adds_pending_metadata_when_requester_submits() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let metadata = MetadataItemParams {
+			name: vec![1, 2, 3],
+			value: vec![4, 5, 6],
+			submitter: LOC_REQUESTER_ID,
+		};
+		assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
+		let item = LogionLoc::get_loc_metadata(LOC_ID)[0].clone();
+		assert!(!item.acknowledged);
+		assert_eq!(Balances::reserved_balance(LOC_REQUESTER_ID), LogionLoc::deposit_for(item.encoded_size()));
+		assert_err!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID), Error::<Test>::CannotCloseUnacknowledged);
+	});
+}
+
+#[test]
+fn it_fails_adding_metadata_when_requester_submits_for_someone_else() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let metadata = MetadataItemParams {
+			name: vec![1, 2, 3],
+			value: vec![4, 5, 6],
+			submitter: LOC_OWNER1,
+		};
+		assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata), Error::<Test>::Unauthorized);
+	});
+}
+
+#[test]
+fn it_acknowledges_metadata() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let loc_deposit = LogionLoc::deposits(LOC_ID, LOC_OWNER1);
+		let metadata = MetadataItemParams {
+			name: vec![1, 2, 3],
+			value: vec![4, 5, 6],
+			submitter: LOC_REQUESTER_ID,
+		};
+		assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
+		assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name.clone()));
+		let item = LogionLoc::get_loc_metadata(LOC_ID)[0].clone();
+		assert!(item.acknowledged);
+		assert_eq!(Balances::reserved_balance(LOC_REQUESTER_ID), 0);
+		assert_eq!(LogionLoc::deposits(LOC_ID, LOC_OWNER1), loc_deposit + LogionLoc::deposit_for(item.encoded_size()));
+		assert_err!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name), Error::<Test>::ItemAlreadyAcknowledged);
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+	});
+}
+
+#[test]
+fn it_fails_acknowledging_metadata_for_unauthorized_caller() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let metadata = MetadataItemParams {
+			name: vec![1, 2, 3],
+			value: vec![4, 5, 6],
+			submitter: LOC_REQUESTER_ID,
+		};
+		assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
+		assert_err!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.name), Error::<Test>::Unauthorized);
+	});
+}
+
+#[test]
+fn it_acknowledges_file() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let file = FileParams {
+			hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
+			nature: "test-file-nature".as_bytes().to_vec(),
+			submitter: LOC_REQUESTER_ID,
+		};
+		assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()));
+		assert_err!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID), Error::<Test>::CannotCloseUnacknowledged);
+		assert_ok!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash));
+		assert!(LogionLoc::get_loc_files(LOC_ID)[0].acknowledged);
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+	});
+}
+
+#[test]
+fn it_releases_requester_deposit_when_deleting_pending_file() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let file = FileParams {
+			hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
+			nature: "test-file-nature".as_bytes().to_vec(),
+			submitter: LOC_REQUESTER_ID,
+		};
+		assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()));
+		assert_ok!(LogionLoc::delete_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash));
+		assert_eq!(Balances::reserved_balance(LOC_REQUESTER_ID), 0);
+		assert_eq!(LogionLoc::deposits(LOC_ID, LOC_REQUESTER_ID), 0);
+	});
+}
+
+#[test]
+fn it_emits_loc_created_event() {
+	new_test_ext_at_block(1).execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::LocCreated(LOC_ID, LOC_OWNER1, LOC_REQUESTER, LocType::Transaction)));
+	});
+}
+
+#[test]
+fn it_emits_item_added_events() {
+	new_test_ext_at_block(1).execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOC_REQUESTER_ID));
+
+		let metadata = MetadataItemParams {
+			name: vec![1, 2, 3],
+			value: vec![4, 5, 6],
+			submitter: LOC_REQUESTER_ID,
+		};
+		assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
+		System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::MetadataAdded(LOC_ID, metadata.name, LOC_REQUESTER_ID)));
+
+		let file = FileParams {
+			hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
+			nature: "test-file-nature".as_bytes().to_vec(),
+			submitter: LOC_OWNER1,
+		};
+		assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()));
+		System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::FileAdded(LOC_ID, file.hash, LOC_OWNER1)));
+
+		let link = LocLinkParams {
+			id: OTHER_LOC_ID,
+			nature: "test-link-nature".as_bytes().to_vec()
+		};
+		assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link));
+		System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::LinkAdded(LOC_ID, OTHER_LOC_ID, LOC_OWNER1)));
+	});
+}
+
+#[test]
+fn it_emits_loc_sealed_event() {
+	new_test_ext_at_block(1).execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let seal = BlakeTwo256::hash_of(&"some external private data".as_bytes().to_vec());
+		assert_ok!(LogionLoc::close_and_seal(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, seal));
+		System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocClosed(LOC_ID)));
+		System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::LocSealed(LOC_ID, seal)));
+	});
+}
+
+#[test]
+fn it_updates_collection_item_and_keeps_revision() {
+	new_test_ext_at_block(1).execute_with(|| {
+		assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID, Option::None, Option::Some(1), false));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+		let collection_item_description = "item-description".as_bytes().to_vec();
+		assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], Option::None, false));
+		let first_version = LogionLoc::collection_items(LOC_ID, collection_item_id).unwrap();
+
+		let new_description = "new-item-description".as_bytes().to_vec();
+		assert_ok!(LogionLoc::update_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, new_description.clone(), vec![], Option::None, false, vec![]));
+
+		assert_eq!(LogionLoc::collection_items(LOC_ID, collection_item_id).unwrap().description.to_vec(), new_description);
+		assert_eq!(LogionLoc::collection_item_revisions((LOC_ID, collection_item_id, 0)), Some(first_version));
+		assert_eq!(LogionLoc::collection_item_revision(LOC_ID, collection_item_id), 1);
+		assert_eq!(LogionLoc::collection_size(LOC_ID), Some(1));
+		System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::ItemUpdated(LOC_ID, collection_item_id, 1)));
+	});
+}
+
+#[test]
+fn it_reindexes_files_and_terms_and_conditions_on_update() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID));
+		assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID, Option::None, Option::Some(1), true));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+		let first_hash = BlakeTwo256::hash_of(&"first content".as_bytes().to_vec());
+		let second_hash = BlakeTwo256::hash_of(&"second content".as_bytes().to_vec());
+		let file = |hash| CollectionItemFileParams {
+			name: "picture.png".as_bytes().to_vec(),
+			content_type: "image/png".as_bytes().to_vec(),
+			hash,
+			size: 123456,
+		};
+		let terms_and_conditions = vec![TermsAndConditionsElementParams {
+			tc_type: "Logion".as_bytes().to_vec(),
+			tc_loc: OTHER_LOC_ID,
+			details: vec![],
+		}];
+		assert_ok!(LogionLoc::add_collection_item_with_terms_and_conditions(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, vec![], vec![file(first_hash)], Option::None, false, terms_and_conditions));
+		assert_eq!(LogionLoc::get_linked_from(OTHER_LOC_ID), vec![LOC_ID]);
+
+		assert_ok!(LogionLoc::update_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, vec![], vec![file(second_hash)], Option::None, false, vec![]));
+
+		assert!(LogionLoc::find_by_file_hash(first_hash).is_empty());
+		assert_eq!(LogionLoc::find_by_file_hash(second_hash), vec![(LOC_ID, Some(collection_item_id))]);
+		assert!(LogionLoc::get_linked_from(OTHER_LOC_ID).is_empty());
+	});
+}
+
+#[test]
+fn it_fails_updating_missing_collection_item() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID, Option::None, Option::Some(1), false));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+		let collection_item_description = "item-description".as_bytes().to_vec();
+		assert_err!(LogionLoc::update_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], Option::None, false, vec![]), Error::<Test>::CollectionItemNotFound);
+	});
+}
+
+#[test]
+fn it_fails_updating_collection_item_for_unauthorized_caller() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID, Option::None, Option::Some(1), false));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+		let collection_item_description = "item-description".as_bytes().to_vec();
+		assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], Option::None, false));
+		assert_err!(LogionLoc::update_collection_item(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, collection_item_id, collection_item_description, vec![], Option::None, false, vec![]), Error::<Test>::WrongCollectionLoc);
+	});
+}
+
+#[test]
+fn it_fails_updating_collection_item_if_block_limit_reached() {
+	let current_block: u64 = 10;
+	new_test_ext_at_block(current_block).execute_with(|| {
+		assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID, Option::Some(current_block + 1), Option::None, false));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+		let collection_item_description = "item-description".as_bytes().to_vec();
+		assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], Option::None, false));
+
+		System::set_block_number(current_block + 1);
+		assert_err!(LogionLoc::update_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], Option::None, false, vec![]), Error::<Test>::CollectionLimitsReached);
+	});
+}
+
+fn create_collection_with_items(item_ids: &[H256]) {
+	assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID, Option::None, Option::Some(10), false));
+	assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+	for item_id in item_ids {
+		let collection_item_description = "item-description".as_bytes().to_vec();
+		assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, *item_id, collection_item_description, vec![], Option::None, false));
+	}
+}
+
+#[test]
+fn it_makes_collection_item_void() {
+	new_test_ext_at_block(1).execute_with(|| {
+		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+		create_collection_with_items(&[collection_item_id]);
+		assert_ok!(LogionLoc::make_item_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, collection_item_id, None));
+		let item = LogionLoc::collection_items(LOC_ID, collection_item_id).unwrap();
+		assert_eq!(item.void_info, Some(CollectionItemVoidInfo { replacer: None }));
+		System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::ItemVoid(LOC_ID, collection_item_id)));
+
+		let collection_item_description = "item-description".as_bytes().to_vec();
+		assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], Option::None, false), Error::<Test>::CollectionItemAlreadyExists);
+		assert_err!(LogionLoc::update_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], Option::None, false, vec![]), Error::<Test>::CannotMutateVoid);
+		assert_err!(LogionLoc::make_item_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, collection_item_id, None), Error::<Test>::AlreadyVoid);
+	});
+}
+
+#[test]
+fn it_makes_collection_item_void_and_replace_it() {
+	new_test_ext().execute_with(|| {
+		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+		let replacer_item_id = BlakeTwo256::hash_of(&"replacer-item-id".as_bytes().to_vec());
+		create_collection_with_items(&[collection_item_id, replacer_item_id]);
+		assert_ok!(LogionLoc::make_item_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, collection_item_id, Some(replacer_item_id)));
+		let item = LogionLoc::collection_items(LOC_ID, collection_item_id).unwrap();
+		assert_eq!(item.void_info, Some(CollectionItemVoidInfo { replacer: Some(replacer_item_id) }));
+		let replacer_item = LogionLoc::collection_items(LOC_ID, replacer_item_id).unwrap();
+		assert_eq!(replacer_item.replacer_of, Some(collection_item_id));
+	});
+}
+
+#[test]
+fn it_fails_making_collection_item_void_for_unauthorized_caller() {
+	new_test_ext().execute_with(|| {
+		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+		create_collection_with_items(&[collection_item_id]);
+		assert_err!(LogionLoc::make_item_void(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, None), Error::<Test>::Unauthorized);
+	});
+}
+
+#[test]
+fn it_fails_replacing_collection_item_with_missing_or_void_item() {
+	new_test_ext().execute_with(|| {
+		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+		let other_item_id = BlakeTwo256::hash_of(&"other-item-id".as_bytes().to_vec());
+		let missing_item_id = BlakeTwo256::hash_of(&"missing-item-id".as_bytes().to_vec());
+		create_collection_with_items(&[collection_item_id, other_item_id]);
+		assert_err!(LogionLoc::make_item_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, collection_item_id, Some(missing_item_id)), Error::<Test>::ReplacerItemNotFound);
+		assert_ok!(LogionLoc::make_item_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, other_item_id, None));
+		assert_err!(LogionLoc::make_item_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, collection_item_id, Some(other_item_id)), Error::<Test>::ReplacerItemAlreadyVoid);
+	});
+}
+
+fn collection_item_params(item_id: H256) -> CollectionItemParams<H256, H256, u32> {
+	CollectionItemParams {
+		id: item_id,
+		description: "item-description".as_bytes().to_vec(),
+		files: vec![],
+		token: Option::None,
+		restricted_delivery: false,
+		terms_and_conditions: vec![],
+	}
+}
+
+#[test]
+fn it_adds_collection_items_in_batch() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID, Option::None, Option::Some(2), false));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+		let owner_balance = Balances::free_balance(LOC_OWNER1);
+
+		let first_item_id = BlakeTwo256::hash_of(&"item-id-1".as_bytes().to_vec());
+		let second_item_id = BlakeTwo256::hash_of(&"item-id-2".as_bytes().to_vec());
+		assert_ok!(LogionLoc::add_collection_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, vec![collection_item_params(first_item_id), collection_item_params(second_item_id)].try_into().unwrap()));
+
+		assert!(LogionLoc::collection_items(LOC_ID, first_item_id).is_some());
+		assert!(LogionLoc::collection_items(LOC_ID, second_item_id).is_some());
+		assert_eq!(LogionLoc::collection_size(LOC_ID), Some(2));
+		let legal_officer_fee = 2 * CollectionItemFee::get() - TreasuryShare::get() * (2 * CollectionItemFee::get());
+		assert_eq!(Balances::free_balance(LOC_OWNER1), owner_balance + legal_officer_fee);
+	});
+}
+
+#[test]
+fn it_fails_adding_collection_items_in_batch_if_limits_reached() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID, Option::None, Option::Some(1), false));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+		let first_item_id = BlakeTwo256::hash_of(&"item-id-1".as_bytes().to_vec());
+		let second_item_id = BlakeTwo256::hash_of(&"item-id-2".as_bytes().to_vec());
+		assert_err!(LogionLoc::add_collection_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, vec![collection_item_params(first_item_id), collection_item_params(second_item_id)].try_into().unwrap()), Error::<Test>::CollectionLimitsReached);
+		assert!(LogionLoc::collection_items(LOC_ID, first_item_id).is_none());
+		assert_eq!(LogionLoc::collection_size(LOC_ID), None);
+	});
+}
+
+#[test]
+fn it_fails_adding_collection_items_in_batch_if_one_is_invalid() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID, Option::None, Option::Some(10), false));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+		let first_item_id = BlakeTwo256::hash_of(&"item-id-1".as_bytes().to_vec());
+		let second_item_id = BlakeTwo256::hash_of(&"item-id-2".as_bytes().to_vec());
+		let mut invalid_item = collection_item_params(second_item_id);
+		invalid_item.restricted_delivery = true;
+		assert_err!(LogionLoc::add_collection_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, vec![collection_item_params(first_item_id), invalid_item].try_into().unwrap()), Error::<Test>::MissingToken);
+		assert!(LogionLoc::collection_items(LOC_ID, first_item_id).is_none());
+	});
+}
+
+#[test]
+fn it_fails_adding_collection_items_in_batch_with_duplicate_ids() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID, Option::None, Option::Some(10), false));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+		let item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+		assert_err!(LogionLoc::add_collection_items(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, vec![collection_item_params(item_id), collection_item_params(item_id)].try_into().unwrap()), Error::<Test>::CollectionItemAlreadyExists);
+	});
+}
+
+#[test]
+fn it_transfers_ownership_with_deposit() {
+	new_test_ext_at_block(1).execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let loc_deposit = LogionLoc::deposits(LOC_ID, LOC_OWNER1);
+
+		assert_ok!(LogionLoc::transfer_ownership(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_OWNER2));
+
+		assert_eq!(LogionLoc::loc(LOC_ID).unwrap().owner, LOC_OWNER2);
+		assert_eq!(Balances::reserved_balance(LOC_OWNER1), 0);
+		assert_eq!(Balances::reserved_balance(LOC_OWNER2), loc_deposit);
+		assert_eq!(LogionLoc::deposits(LOC_ID, LOC_OWNER1), 0);
+		assert_eq!(LogionLoc::deposits(LOC_ID, LOC_OWNER2), loc_deposit);
+		assert!(!crate::LocsByOwner::<Test>::contains_key(LOC_OWNER1, LOC_ID));
+		assert!(crate::LocsByOwner::<Test>::contains_key(LOC_OWNER2, LOC_ID));
+		System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::OwnershipTransferred(LOC_ID, LOC_OWNER1, LOC_OWNER2)));
+	});
+}
+
+#[test]
+fn it_transfers_ownership_with_force_origin() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::transfer_ownership(RuntimeOrigin::root(), LOC_ID, LOC_OWNER2));
+		assert_eq!(LogionLoc::loc(LOC_ID).unwrap().owner, LOC_OWNER2);
+	});
+}
+
+#[test]
+fn it_fails_transferring_ownership_for_unauthorized_caller() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_err!(LogionLoc::transfer_ownership(RuntimeOrigin::signed(LOC_OWNER2), LOC_ID, LOC_OWNER2), Error::<Test>::Unauthorized);
+	});
+}
+
+#[test]
+fn it_fails_transferring_ownership_to_non_legal_officer() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_err!(LogionLoc::transfer_ownership(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID), Error::<Test>::NewOwnerNotLegalOfficer);
+		assert_err!(LogionLoc::transfer_all_ownership(RuntimeOrigin::root(), LOC_OWNER1, LOC_REQUESTER_ID, 10), Error::<Test>::NewOwnerNotLegalOfficer);
+		assert_eq!(LogionLoc::loc(LOC_ID).unwrap().owner, LOC_OWNER1);
+	});
+}
+
+#[test]
+fn it_fails_transferring_ownership_of_missing_loc() {
+	new_test_ext().execute_with(|| {
+		assert_err!(LogionLoc::transfer_ownership(RuntimeOrigin::root(), LOC_ID, LOC_OWNER2), Error::<Test>::NotFound);
+	});
+}
+
+#[test]
+fn it_transfers_all_ownership_up_to_limit() {
+	new_test_ext_at_block(1).execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOC_REQUESTER_ID));
+
+		assert_ok!(LogionLoc::transfer_all_ownership(RuntimeOrigin::root(), LOC_OWNER1, LOC_OWNER2, 1));
+		System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::OwnershipBulkTransferred(LOC_OWNER1, LOC_OWNER2, 1)));
+		assert_eq!(crate::LocsByOwner::<Test>::iter_key_prefix(LOC_OWNER1).count(), 1);
+
+		let post_info = LogionLoc::transfer_all_ownership(RuntimeOrigin::signed(LOC_OWNER1), LOC_OWNER1, LOC_OWNER2, 10).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as crate::weights::WeightInfo>::transfer_all_ownership(1)));
+		System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::OwnershipBulkTransferred(LOC_OWNER1, LOC_OWNER2, 1)));
+		assert_eq!(crate::LocsByOwner::<Test>::iter_key_prefix(LOC_OWNER1).count(), 0);
+		assert_eq!(LogionLoc::loc(LOC_ID).unwrap().owner, LOC_OWNER2);
+		assert_eq!(LogionLoc::loc(OTHER_LOC_ID).unwrap().owner, LOC_OWNER2);
+		assert_eq!(Balances::reserved_balance(LOC_OWNER1), 0);
+	});
+}
+
+#[test]
+fn it_fails_transferring_all_ownership_for_unauthorized_caller() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_err!(LogionLoc::transfer_all_ownership(RuntimeOrigin::signed(LOC_OWNER2), LOC_OWNER1, LOC_OWNER2, 10), Error::<Test>::Unauthorized);
+	});
+}
+
+#[test]
+fn it_indexes_locs_by_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER2), OTHER_LOC_ID, LOC_REQUESTER_ID));
+
+		let mut owner1_locs = LogionLoc::get_owner_locs(LOC_OWNER1, None, 10);
+		owner1_locs.sort();
+		assert_eq!(owner1_locs, vec![LOC_ID, LOGION_IDENTITY_LOC_ID]);
+		assert_eq!(LogionLoc::get_owner_locs(LOC_OWNER2, None, 10), vec![OTHER_LOC_ID]);
+	});
+}
+
+#[test]
+fn it_paginates_owner_locs() {
+	new_test_ext().execute_with(|| {
+		for loc_id in 0..5 {
+			assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), loc_id));
+		}
+
+		let first_page = LogionLoc::get_owner_locs(LOC_OWNER1, None, 2);
+		assert_eq!(first_page.len(), 2);
+		let second_page = LogionLoc::get_owner_locs(LOC_OWNER1, first_page.last().copied(), 2);
+		assert_eq!(second_page.len(), 2);
+		let last_page = LogionLoc::get_owner_locs(LOC_OWNER1, second_page.last().copied(), 2);
+		assert_eq!(last_page.len(), 1);
+
+		let mut all_locs = [first_page, second_page, last_page].concat();
+		all_locs.sort();
+		assert_eq!(all_locs, vec![0, 1, 2, 3, 4]);
+	});
+}
+
+#[test]
+fn it_finds_locs_and_items_by_file_hash() {
+	new_test_ext().execute_with(|| {
+		let hash = BlakeTwo256::hash_of(&"file content".as_bytes().to_vec());
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOC_REQUESTER_ID));
+		let file = FileParams {
+			hash,
+			nature: "test-file-nature".as_bytes().to_vec(),
+			submitter: LOC_OWNER1,
+		};
+		assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, file));
+
+		assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID, Option::None, Option::Some(1), true));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+		let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+		let collection_item_files = vec![CollectionItemFileParams {
+			name: "picture.png".as_bytes().to_vec(),
+			content_type: "image/png".as_bytes().to_vec(),
+			hash,
+			size: 123456,
+		}];
+		assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, "item-description".as_bytes().to_vec(), collection_item_files, Option::None, false));
+
+		let mut found = LogionLoc::find_by_file_hash(hash);
+		found.sort();
+		assert_eq!(found, vec![(LOC_ID, Some(collection_item_id)), (OTHER_LOC_ID, None)]);
+		assert!(LogionLoc::find_by_file_hash(BlakeTwo256::hash_of(&"other content".as_bytes().to_vec())).is_empty());
+	});
+}
+
+#[test]
+fn it_removes_deleted_file_from_hash_index() {
+	new_test_ext().execute_with(|| {
+		let hash = BlakeTwo256::hash_of(&"file content".as_bytes().to_vec());
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let file = FileParams {
+			hash,
+			nature: "test-file-nature".as_bytes().to_vec(),
+			submitter: LOC_OWNER1,
+		};
+		assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file));
+		assert_eq!(LogionLoc::find_by_file_hash(hash), vec![(LOC_ID, None)]);
+
+		assert_ok!(LogionLoc::delete_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, hash));
+		assert!(LogionLoc::find_by_file_hash(hash).is_empty());
+		assert!(!crate::FileHashIndex::<Test>::contains_key(hash, (LOC_ID, None)));
+	});
+}
+
+#[test]
+fn it_indexes_links_by_target() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOC_REQUESTER_ID));
+		let link = LocLinkParams {
+			id: OTHER_LOC_ID,
+			nature: "test-link-nature".as_bytes().to_vec()
+		};
+		assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link));
+		assert_eq!(LogionLoc::get_linked_from(OTHER_LOC_ID), vec![LOC_ID]);
+		assert!(LogionLoc::get_linked_from(LOC_ID).is_empty());
+
+		assert_ok!(LogionLoc::delete_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, OTHER_LOC_ID));
+		assert!(LogionLoc::get_linked_from(OTHER_LOC_ID).is_empty());
+	});
+}
+
+#[test]
+fn it_warns_about_dependents_when_voiding_linked_loc() {
+	new_test_ext_at_block(1).execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOC_REQUESTER_ID));
+		let link = LocLinkParams {
+			id: OTHER_LOC_ID,
+			nature: "test-link-nature".as_bytes().to_vec()
+		};
+		assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link));
+
+		assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID));
+		System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::VoidLocHasDependents(OTHER_LOC_ID, vec![LOC_ID])));
+
+		assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+		System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::LocVoid(LOC_ID)));
+	});
+}
+
+#[test]
+fn it_reports_a_bounded_number_of_dependents_when_voiding() {
+	new_test_ext_at_block(1).execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		let dependents = crate::MAX_VOID_DEPENDENTS + 2;
+		for loc_id in 100..(100 + dependents) {
+			assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), loc_id, LOC_REQUESTER_ID));
+			let link = LocLinkParams {
+				id: LOC_ID,
+				nature: "test-link-nature".as_bytes().to_vec()
+			};
+			assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), loc_id, link));
+		}
+
+		let post_info = LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID).unwrap();
+		let expected_weight = <() as crate::WeightInfo>::make_void()
+			.saturating_add(<Test as frame_system::Config>::DbWeight::get().reads(crate::MAX_VOID_DEPENDENTS as u64));
+		assert_eq!(post_info.actual_weight, Some(expected_weight));
+		let reported = System::events().into_iter().find_map(|record| match record.event {
+			RuntimeEvent::LogionLoc(crate::Event::VoidLocHasDependents(LOC_ID, reported)) => Some(reported),
+			_ => None,
+		}).unwrap();
+		assert_eq!(reported.len(), crate::MAX_VOID_DEPENDENTS as usize);
+	});
+}
+
+#[test]
+fn it_indexes_closed_identity_locs() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_eq!(LogionLoc::closed_identity_locs(LOC_REQUESTER_ID, LOC_OWNER1), None);
+
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+		assert_eq!(LogionLoc::closed_identity_locs(LOC_REQUESTER_ID, LOC_OWNER1), Some(LOC_ID));
+
+		assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+		assert_eq!(LogionLoc::closed_identity_locs(LOC_REQUESTER_ID, LOC_OWNER1), None);
+	});
+}
+
+#[test]
+fn it_falls_back_to_other_closed_identity_loc_on_void() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+		assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID));
+
+		assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID));
+		assert_eq!(LogionLoc::closed_identity_locs(LOC_REQUESTER_ID, LOC_OWNER1), Some(LOC_ID));
+		assert!(!crate::AllClosedIdentityLocs::<Test>::contains_key((LOC_REQUESTER_ID, LOC_OWNER1), OTHER_LOC_ID));
+
+		assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+		assert_eq!(LogionLoc::closed_identity_locs(LOC_REQUESTER_ID, LOC_OWNER1), None);
+	});
+}
+
+#[test]
+fn it_reindexes_closed_identity_loc_on_ownership_transfer() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+		assert_ok!(LogionLoc::transfer_ownership(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_OWNER2));
+		assert_eq!(LogionLoc::closed_identity_locs(LOC_REQUESTER_ID, LOC_OWNER1), None);
+		assert_eq!(LogionLoc::closed_identity_locs(LOC_REQUESTER_ID, LOC_OWNER2), Some(LOC_ID));
+	});
+}
+
+#[test]
+fn it_finds_closed_identity_locs_with_threshold() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+		let legal_officers = Vec::from([LOC_OWNER1, LOC_OWNER2]);
+		assert_eq!(LogionLoc::find_closed_identity_locs(&LOC_REQUESTER_ID, &legal_officers, 1), Some(vec![LOC_ID]));
+		assert_eq!(LogionLoc::find_closed_identity_locs(&LOC_REQUESTER_ID, &legal_officers, 2), None);
+		assert_eq!(LogionLoc::find_closed_identity_locs(&LOC_REQUESTER_ID, &Vec::from([LOC_OWNER1, LOC_OWNER1]), 2), None);
+		assert!(!LogionLoc::has_closed_identity_locs(&LOC_REQUESTER_ID, &legal_officers));
+		assert!(LogionLoc::has_closed_identity_locs(&LOC_REQUESTER_ID, &Vec::from([LOC_OWNER1, LOC_OWNER1])));
+	});
+}
+
+#[test]
+fn it_does_not_panic_with_less_than_two_legal_officers() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+		assert!(!LogionLoc::has_closed_identity_locs(&LOC_REQUESTER_ID, &Vec::from([LOC_OWNER1])));
+		assert!(!LogionLoc::has_closed_identity_locs(&LOC_REQUESTER_ID, &Vec::new()));
+	});
+}
+
+#[test]
+fn it_paginates_account_locs_including_legacy_ones() {
+	new_test_ext().execute_with(|| {
+		crate::AccountLocsMap::<Test>::insert(LOC_REQUESTER_ID, vec![10, 11, 12]);
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOC_REQUESTER_ID));
+		assert_eq!(LogionLoc::get_account_locs_count(LOC_REQUESTER_ID), 5);
+
+		let first_page = LogionLoc::get_account_locs(LOC_REQUESTER_ID, None, 2);
+		assert_eq!(first_page, vec![10, 11]);
+		let second_page = LogionLoc::get_account_locs(LOC_REQUESTER_ID, Some(11), 2);
+		assert_eq!(second_page.len(), 2);
+		assert_eq!(second_page[0], 12);
+		let last_page = LogionLoc::get_account_locs(LOC_REQUESTER_ID, second_page.last().copied(), 2);
+		assert_eq!(last_page.len(), 1);
+
+		let mut new_locs = vec![second_page[1], last_page[0]];
+		new_locs.sort();
+		assert_eq!(new_locs, vec![LOC_ID, OTHER_LOC_ID]);
+	});
+}
+
+#[test]
+fn it_migrates_legacy_loc_indexes_on_idle() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::OnRuntimeUpgrade;
+		use crate::migrations::multi_block::{MIGRATION_WEIGHT_PER_BYTE, StartMultiBlockMigration};
+
+		StorageVersion::new(16).put::<LogionLoc>();
+		let account_loc_ids = vec![LOC_ID, OTHER_LOC_ID];
+		crate::AccountLocsMap::<Test>::insert(LOC_REQUESTER_ID, account_loc_ids.clone());
+		crate::IdentityLocLocsMap::<Test>::insert(LOGION_IDENTITY_LOC_ID, vec![LOC_ID]);
+
+		StartMultiBlockMigration::<Test>::on_runtime_upgrade();
+
+		assert!(LogionLoc::ongoing_migration().is_some());
+		assert_err!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID), Error::<Test>::MigrationInProgress);
+		let one_list_weight = Weight::from_ref_time(2 * account_loc_ids.encoded_size() as u64 * MIGRATION_WEIGHT_PER_BYTE);
+		LogionLoc::on_idle(1, one_list_weight);
+		assert!(crate::AccountLocsMap::<Test>::get(LOC_REQUESTER_ID).is_none());
+		assert!(crate::IdentityLocLocsMap::<Test>::get(LOGION_IDENTITY_LOC_ID).is_some());
+		assert_eq!(LogionLoc::ongoing_migration().unwrap().map_index, 1);
+
+		let mut blocks = 1;
+		while LogionLoc::ongoing_migration().is_some() {
+			LogionLoc::on_idle(1, one_list_weight);
+			blocks += 1;
+		}
+
+		assert_eq!(blocks, 2);
+		assert!(crate::IdentityLocLocsMap::<Test>::get(LOGION_IDENTITY_LOC_ID).is_none());
+		assert_eq!(LogionLoc::get_account_locs_count(LOC_REQUESTER_ID), 2);
+		assert_eq!(LogionLoc::get_identity_loc_locs(LOGION_IDENTITY_LOC_ID, None, 10), vec![LOC_ID]);
+		assert_eq!(LogionLoc::on_chain_storage_version(), StorageVersion::new(18));
+	});
+}
+
+#[test]
+fn it_keeps_items_with_same_key() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOC_REQUESTER_ID));
+		let metadata = |value: u8| MetadataItemParams {
+			name: vec![1, 2, 3],
+			value: vec![value],
+			submitter: LOC_OWNER1,
+		};
+		assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata(4)));
+		assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata(5)));
+		let file = FileParams {
+			hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
+			nature: "test-file-nature".as_bytes().to_vec(),
+			submitter: LOC_OWNER1,
+		};
+		assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()));
+		assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()));
+		let link = LocLinkParams {
+			id: OTHER_LOC_ID,
+			nature: "test-link-nature".as_bytes().to_vec()
+		};
+		assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link.clone()));
+		assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link.clone()));
+
+		let loc = LogionLoc::loc(LOC_ID).unwrap();
+		assert_eq!((loc.metadata_count, loc.files_count, loc.links_count), (2, 2, 2));
+		let expected_metadata: MetadataItemOf<Test> = metadata(5).try_into().unwrap();
+		assert_ok!(LogionLoc::delete_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, vec![1, 2, 3]));
+		assert_eq!(LogionLoc::get_loc_metadata(LOC_ID), vec![expected_metadata]);
+
+		assert_ok!(LogionLoc::delete_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash));
+		assert_eq!(LogionLoc::get_loc_files(LOC_ID).len(), 1);
+		assert_eq!(LogionLoc::find_by_file_hash(file.hash), vec![(LOC_ID, None)]);
+		assert_ok!(LogionLoc::delete_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash));
+		assert!(LogionLoc::find_by_file_hash(file.hash).is_empty());
+
+		assert_ok!(LogionLoc::delete_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, OTHER_LOC_ID));
+		assert_eq!(LogionLoc::get_loc_links(LOC_ID).len(), 1);
+		assert_eq!(LogionLoc::get_linked_from(OTHER_LOC_ID), vec![LOC_ID]);
+	});
+}
+
+#[test]
+fn it_splits_loc_content_when_migrating_from_v16() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::OnRuntimeUpgrade;
+		use crate::migrations::multi_block::{MIGRATION_WEIGHT_PER_BYTE, StartMultiBlockMigration};
+		use crate::migrations::v18::{LegalOfficerCaseV17, LocMap as LocMapV17};
+
+		let metadata: MetadataItemOf<Test> = MetadataItemParams {
+			name: vec![1, 2, 3],
+			value: vec![4, 5, 6],
+			submitter: LOC_OWNER1,
+		}.try_into().unwrap();
+		let file: FileOf<Test> = FileParams {
+			hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
+			nature: "test-file-nature".as_bytes().to_vec(),
+			submitter: LOC_REQUESTER_ID,
+		}.try_into().unwrap();
+		let link: LocLinkOf<Test> = LocLinkParams {
+			id: OTHER_LOC_ID,
+			nature: "test-link-nature".as_bytes().to_vec()
+		}.try_into().unwrap();
+		let legacy_loc = |metadata: Vec<MetadataItemOf<Test>>, files: Vec<FileOf<Test>>, links: Vec<LocLinkOf<Test>>| LegalOfficerCaseV17::<Test> {
+			owner: LOC_OWNER1,
+			requester: LOC_REQUESTER,
+			metadata: metadata.try_into().unwrap(),
+			files: files.try_into().unwrap(),
+			closed: false,
+			loc_type: LocType::Transaction,
+			links: links.try_into().unwrap(),
+			void_info: None,
+			replacer_of: None,
+			collection_last_block_submission: None,
+			collection_max_size: None,
+			collection_can_upload: false,
+			seal: None,
+		};
+		StorageVersion::new(16).put::<LogionLoc>();
+		let loc = legacy_loc(vec![metadata.clone(), metadata.clone()], vec![file.clone()], vec![link.clone(), link.clone()]);
+		let loc_size = loc.encoded_size() as u64;
+		LocMapV17::<Test>::insert(LOC_ID, loc);
+		LocMapV17::<Test>::insert(OTHER_LOC_ID, legacy_loc(vec![], vec![], vec![]));
+		crate::AccountLocsMap::<Test>::insert(LOC_REQUESTER_ID, vec![LOC_ID, OTHER_LOC_ID]);
+
+		StartMultiBlockMigration::<Test>::on_runtime_upgrade();
+		let mut blocks = 0;
+		while LogionLoc::ongoing_migration().is_some() {
+			LogionLoc::on_idle(1, Weight::from_ref_time(2 * loc_size * MIGRATION_WEIGHT_PER_BYTE));
+			blocks += 1;
+		}
+
+		assert!(blocks > 1);
+		assert_eq!(LogionLoc::on_chain_storage_version(), StorageVersion::new(18));
+		assert_eq!(crate::LocMap::<Test>::iter_values().count(), 2);
+		let loc = LogionLoc::loc(LOC_ID).unwrap();
+		assert_eq!((loc.metadata_count, loc.files_count, loc.links_count), (2, 1, 2));
+		assert_eq!(LogionLoc::get_loc_metadata(LOC_ID), vec![metadata.clone(), metadata]);
+		assert_eq!(LogionLoc::get_loc_files(LOC_ID), vec![file]);
+		assert_eq!(LogionLoc::get_loc_links(LOC_ID), vec![link.clone(), link]);
+		assert!(LogionLoc::get_loc_metadata(OTHER_LOC_ID).is_empty());
+		assert_eq!(LogionLoc::get_account_locs_count(LOC_REQUESTER_ID), 2);
+		assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID));
+	});
+}
+
+#[test]
+fn it_moves_legacy_storage_version() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::OnRuntimeUpgrade;
+		use crate::migrations::storage_version::{LegacyStorageVersion, MigrateToFrameStorageVersion, PalletStorageVersion};
+
+		PalletStorageVersion::<Test>::put(LegacyStorageVersion::V16ClosedIdentityLocs);
+
+		MigrateToFrameStorageVersion::<Test>::on_runtime_upgrade();
+
+		assert_eq!(LogionLoc::on_chain_storage_version(), StorageVersion::new(16));
+		assert!(!PalletStorageVersion::<Test>::exists());
+
+		PalletStorageVersion::<Test>::put(LegacyStorageVersion::V8AddSeal);
+		MigrateToFrameStorageVersion::<Test>::on_runtime_upgrade();
+		assert_eq!(LogionLoc::on_chain_storage_version(), StorageVersion::new(16));
+	});
+}
+
+#[test]
+fn it_detects_v9_layout_without_legacy_storage_version() {
+	new_test_ext().execute_with(|| {
+		use frame_support::storage::unhashed;
+		use frame_support::traits::OnRuntimeUpgrade;
+		use crate::migrations::storage_version::MigrateToFrameStorageVersion;
+		use crate::migrations::v9::AddTermsAndConditionsToCollectionItem;
+
+		StorageVersion::new(0).put::<LogionLoc>();
+		let item_key = crate::CollectionItemsMap::<Test>::hashed_key_for(LOC_ID, BlakeTwo256::hash_of(&0));
+		unhashed::put(&item_key, &legacy_collection_item_v9());
+
+		MigrateToFrameStorageVersion::<Test>::on_runtime_upgrade();
+		AddTermsAndConditionsToCollectionItem::<Test>::on_runtime_upgrade();
+
+		assert_eq!(LogionLoc::on_chain_storage_version(), StorageVersion::new(9));
+		assert_eq!(unhashed::get(&item_key), Some(legacy_collection_item_v9()));
+	});
+}
+
+#[test]
+fn it_detects_v8_layout_without_legacy_storage_version() {
+	new_test_ext().execute_with(|| {
+		use frame_support::storage::unhashed;
+		use frame_support::traits::OnRuntimeUpgrade;
+		use crate::migrations::storage_version::MigrateToFrameStorageVersion;
+		use crate::migrations::v10::{CollectionItemFileV9, CollectionItemTokenV9};
+
+		StorageVersion::new(0).put::<LogionLoc>();
+		let item_v8 = (vec![1u8], Vec::<CollectionItemFileV9<H256>>::new(), None::<CollectionItemTokenV9>, false);
+		unhashed::put(&crate::CollectionItemsMap::<Test>::hashed_key_for(LOC_ID, BlakeTwo256::hash_of(&0)), &item_v8);
+
+		MigrateToFrameStorageVersion::<Test>::on_runtime_upgrade();
+
+		assert_eq!(LogionLoc::on_chain_storage_version(), StorageVersion::new(8));
+	});
+}
+
+#[test]
+fn it_bounds_locs_and_collection_items_when_migrating_from_v9() {
+	new_test_ext().execute_with(|| {
+		use frame_support::storage::unhashed;
+
+		unhashed::put(&crate::LocMap::<Test>::hashed_key_for(LOC_ID), &legacy_loc_v9(vec![1, 2, 3]));
+		let item_id = BlakeTwo256::hash_of(&0);
+		unhashed::put(&crate::CollectionItemsMap::<Test>::hashed_key_for(LOC_ID, item_id), &legacy_collection_item_v9());
+
+		migrate_on_idle(9);
+
+		assert_eq!(LogionLoc::on_chain_storage_version(), StorageVersion::new(18));
+		let metadata = LogionLoc::get_loc_metadata(LOC_ID);
+		assert_eq!(metadata[0].name.to_vec(), vec![1, 2, 3]);
+		assert_eq!(metadata[0].value.to_vec(), vec![4, 5, 6]);
+		assert!(metadata[0].acknowledged);
+		assert_eq!(LogionLoc::get_loc_files(LOC_ID)[0].nature.to_vec(), vec![7]);
+		assert_eq!(LogionLoc::get_loc_links(LOC_ID)[0].id, OTHER_LOC_ID);
+		let item = LogionLoc::collection_items(LOC_ID, item_id).unwrap();
+		assert_eq!(item.description.to_vec(), vec![1]);
+		assert_eq!(item.files[0].name.to_vec(), vec![2]);
+		assert_eq!(item.token.unwrap().token_id.to_vec(), vec![6]);
+		assert_eq!(item.terms_and_conditions[0].details.to_vec(), vec![8]);
+		assert_eq!(item.void_info, None);
+		assert!(crate::LocsByOwner::<Test>::contains_key(LOC_OWNER1, LOC_ID));
+		assert_eq!(LogionLoc::get_linked_from(OTHER_LOC_ID), vec![LOC_ID]);
+	});
+}
+
+#[test]
+fn it_refuses_to_bound_locs_exceeding_bounds() {
+	new_test_ext().execute_with(|| {
+		use frame_support::storage::unhashed;
+
+		let exceeding_loc = legacy_loc_v9(vec![0; MaxMetadataItemNameSize::get() as usize + 1]);
+		let exceeding_loc_key = crate::LocMap::<Test>::hashed_key_for(LOC_ID);
+		unhashed::put(&exceeding_loc_key, &exceeding_loc);
+		let loc = legacy_loc_v9(vec![1, 2, 3]);
+		let loc_key = crate::LocMap::<Test>::hashed_key_for(OTHER_LOC_ID);
+		unhashed::put(&loc_key, &loc);
+		let item = legacy_collection_item_v9();
+		let item_key = crate::CollectionItemsMap::<Test>::hashed_key_for(OTHER_LOC_ID, BlakeTwo256::hash_of(&0));
+		unhashed::put(&item_key, &item);
+
+		migrate_on_idle(9);
+
+		assert_eq!(LogionLoc::on_chain_storage_version(), StorageVersion::new(9));
+		assert_eq!(unhashed::get_raw(&exceeding_loc_key), Some(exceeding_loc.encode()));
+		assert_eq!(unhashed::get_raw(&loc_key), Some(loc.encode()));
+		assert_eq!(unhashed::get_raw(&item_key), Some(item.encode()));
+		assert!(LogionLoc::ongoing_migration().is_none());
+		assert_err!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID), Error::<Test>::MigrationInProgress);
+		assert_err!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), 2), Error::<Test>::MigrationInProgress);
+	});
+}
+
+#[test]
+fn it_acknowledges_existing_items_when_migrating_from_v10() {
+	new_test_ext().execute_with(|| {
+		use frame_support::storage::unhashed;
+		use crate::migrations::v11::{FileV10, LegalOfficerCaseV10, MetadataItemV10};
+
+		let loc = LegalOfficerCaseV10::<Test> {
+			owner: LOC_OWNER1,
+			requester: LOC_REQUESTER,
+			metadata: vec![MetadataItemV10::<Test> {
+				name: vec![1, 2, 3].try_into().unwrap(),
+				value: vec![4, 5, 6].try_into().unwrap(),
+				submitter: LOC_REQUESTER_ID,
+			}].try_into().unwrap(),
+			files: vec![FileV10::<Test> {
+				hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
+				nature: vec![7].try_into().unwrap(),
+				submitter: LOC_REQUESTER_ID,
+			}].try_into().unwrap(),
+			closed: false,
+			loc_type: LocType::Transaction,
+			links: BoundedVec::default(),
+			void_info: None,
+			replacer_of: None,
+			collection_last_block_submission: None,
+			collection_max_size: None,
+			collection_can_upload: false,
+			seal: None,
+		};
+		unhashed::put(&crate::LocMap::<Test>::hashed_key_for(LOC_ID), &loc);
+
+		migrate_on_idle(10);
+
+		assert_eq!(LogionLoc::on_chain_storage_version(), StorageVersion::new(18));
+		let metadata = LogionLoc::get_loc_metadata(LOC_ID);
+		assert_eq!(metadata[0].name.to_vec(), vec![1, 2, 3]);
+		assert!(metadata[0].acknowledged);
+		assert!(LogionLoc::get_loc_files(LOC_ID)[0].acknowledged);
+	});
+}
+
+#[test]
+fn it_adds_void_info_to_collection_items_when_migrating_from_v11() {
+	new_test_ext().execute_with(|| {
+		use frame_support::storage::unhashed;
+		use crate::migrations::v12::CollectionItemV11;
+
+		let item_id = BlakeTwo256::hash_of(&0);
+		unhashed::put(&crate::CollectionItemsMap::<Test>::hashed_key_for(LOC_ID, item_id), &CollectionItemV11::<Test> {
+			description: vec![1].try_into().unwrap(),
+			files: BoundedVec::default(),
+			token: None,
+			restricted_delivery: false,
+			terms_and_conditions: BoundedVec::default(),
+		});
+
+		migrate_on_idle(11);
+
+		assert_eq!(LogionLoc::on_chain_storage_version(), StorageVersion::new(18));
+		let item = LogionLoc::collection_items(LOC_ID, item_id).unwrap();
+		assert_eq!(item.description.to_vec(), vec![1]);
+		assert_eq!(item.void_info, None);
+	});
+}
+
+#[test]
+fn it_indexes_locs_by_owner_when_migrating_from_v12() {
+	new_test_ext().execute_with(|| {
+		use crate::migrations::v18::LocMap as LocMapV17;
+
+		LocMapV17::<Test>::insert(LOC_ID, legacy_loc_v17(LocType::Transaction, vec![], vec![]));
+
+		migrate_on_idle(12);
+
+		assert_eq!(LogionLoc::on_chain_storage_version(), StorageVersion::new(18));
+		assert!(crate::LocsByOwner::<Test>::contains_key(LOC_OWNER1, LOC_ID));
+	});
+}
+
+#[test]
+fn it_indexes_file_hashes_when_migrating_from_v13() {
+	new_test_ext().execute_with(|| {
+		use crate::migrations::v18::LocMap as LocMapV17;
+
+		let file: FileOf<Test> = FileParams {
+			hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
+			nature: "test-file-nature".as_bytes().to_vec(),
+			submitter: LOC_OWNER1,
+		}.try_into().unwrap();
+		LocMapV17::<Test>::insert(LOC_ID, legacy_loc_v17(LocType::Transaction, vec![file.clone()], vec![]));
+
+		migrate_on_idle(13);
+
+		assert_eq!(LogionLoc::on_chain_storage_version(), StorageVersion::new(18));
+		assert_eq!(LogionLoc::find_by_file_hash(file.hash), vec![(LOC_ID, None)]);
+	});
+}
+
+#[test]
+fn it_indexes_linked_from_when_migrating_from_v14() {
+	new_test_ext().execute_with(|| {
+		use crate::migrations::v18::LocMap as LocMapV17;
+
+		let link: LocLinkOf<Test> = LocLinkParams {
+			id: OTHER_LOC_ID,
+			nature: "test-link-nature".as_bytes().to_vec()
+		}.try_into().unwrap();
+		LocMapV17::<Test>::insert(LOC_ID, legacy_loc_v17(LocType::Transaction, vec![], vec![link]));
+		LocMapV17::<Test>::insert(OTHER_LOC_ID, legacy_loc_v17(LocType::Transaction, vec![], vec![]));
+
+		migrate_on_idle(14);
+
+		assert_eq!(LogionLoc::on_chain_storage_version(), StorageVersion::new(18));
+		assert_eq!(LogionLoc::get_linked_from(OTHER_LOC_ID), vec![LOC_ID]);
+		assert!(LogionLoc::get_linked_from(LOC_ID).is_empty());
+		assert_eq!(LogionLoc::next_loc_id(), OTHER_LOC_ID + 1);
+	});
+}
+
+#[test]
+fn it_indexes_closed_identity_locs_when_migrating_from_v15() {
+	new_test_ext().execute_with(|| {
+		use crate::migrations::v18::LocMap as LocMapV17;
+
+		let mut identity_loc = legacy_loc_v17(LocType::Identity, vec![], vec![]);
+		identity_loc.closed = true;
+		LocMapV17::<Test>::insert(LOC_ID, identity_loc);
+		LocMapV17::<Test>::insert(OTHER_LOC_ID, legacy_loc_v17(LocType::Identity, vec![], vec![]));
+
+		migrate_on_idle(15);
+
+		assert_eq!(LogionLoc::on_chain_storage_version(), StorageVersion::new(18));
+		assert_eq!(LogionLoc::closed_identity_locs(LOC_REQUESTER_ID, LOC_OWNER1), Some(LOC_ID));
+		assert_eq!(crate::AllClosedIdentityLocs::<Test>::iter_key_prefix((LOC_REQUESTER_ID, LOC_OWNER1)).collect::<Vec<_>>(), vec![LOC_ID]);
+	});
+}
+
+fn migrate_on_idle(from_version: u16) {
+	use frame_support::traits::OnRuntimeUpgrade;
+	use crate::migrations::multi_block::StartMultiBlockMigration;
+
+	StorageVersion::new(from_version).put::<LogionLoc>();
+	StartMultiBlockMigration::<Test>::on_runtime_upgrade();
+	while LogionLoc::ongoing_migration().is_some() {
+		LogionLoc::on_idle(1, Weight::from_ref_time(u64::MAX));
+	}
+}
+
+fn legacy_loc_v9(metadata_name: Vec<u8>) -> crate::migrations::v10::LegalOfficerCaseV9Of<Test> {
+	use crate::migrations::v10::{FileV9, LegalOfficerCaseV9Of, LocLinkV9, MetadataItemV9};
+	LegalOfficerCaseV9Of::<Test> {
+		owner: LOC_OWNER1,
+		requester: LOC_REQUESTER,
+		metadata: vec![MetadataItemV9 { name: metadata_name, value: vec![4, 5, 6], submitter: LOC_OWNER1 }],
+		files: vec![FileV9 { hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()), nature: vec![7], submitter: LOC_REQUESTER_ID }],
+		closed: false,
+		loc_type: LocType::Transaction,
+		links: vec![LocLinkV9 { id: OTHER_LOC_ID, nature: vec![8] }],
+		void_info: None,
+		replacer_of: None,
+		collection_last_block_submission: None,
+		collection_max_size: None,
+		collection_can_upload: false,
+		seal: None,
+	}
+}
+
+fn legacy_collection_item_v9() -> crate::migrations::v10::CollectionItemV9Of<Test> {
+	use crate::migrations::v10::{CollectionItemFileV9, CollectionItemTokenV9, CollectionItemV9Of, TermsAndConditionsElementV9};
+	CollectionItemV9Of::<Test> {
+		description: vec![1],
+		files: vec![CollectionItemFileV9 { name: vec![2], content_type: vec![3], size: 4, hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()) }],
+		token: Some(CollectionItemTokenV9 { token_type: vec![5], token_id: vec![6] }),
+		restricted_delivery: false,
+		terms_and_conditions: vec![TermsAndConditionsElementV9 { tc_type: vec![7], tc_loc: OTHER_LOC_ID, details: vec![8] }],
+	}
+}
+
+fn legacy_loc_v17(loc_type: LocType, files: Vec<FileOf<Test>>, links: Vec<LocLinkOf<Test>>) -> crate::migrations::v18::LegalOfficerCaseV17<Test> {
+	crate::migrations::v18::LegalOfficerCaseV17::<Test> {
+		owner: LOC_OWNER1,
+		requester: LOC_REQUESTER,
+		metadata: BoundedVec::default(),
+		files: files.try_into().unwrap(),
+		closed: false,
+		loc_type,
+		links: links.try_into().unwrap(),
+		void_info: None,
+		replacer_of: None,
+		collection_last_block_submission: None,
+		collection_max_size: None,
+		collection_can_upload: false,
+		seal: None,
+	}
+}
+
+#[test]
+fn it_creates_locs_with_next_id() {
+	new_test_ext_at_block(1).execute_with(|| {
+		assert_ok!(LogionLoc::create_logion_identity_loc_with_next_id(RuntimeOrigin::signed(LOC_OWNER1)));
+		System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::LocCreated(0, LOC_OWNER1, crate::Requester::None, LocType::Identity)));
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc_with_next_id(RuntimeOrigin::signed(LOC_OWNER1), LOC_REQUESTER_ID));
+		System::assert_last_event(RuntimeEvent::LogionLoc(crate::Event::LocCreated(1, LOC_OWNER1, LOC_REQUESTER, LocType::Transaction)));
+		assert_ok!(LogionLoc::create_collection_loc_with_next_id(RuntimeOrigin::signed(LOC_OWNER1), LOC_REQUESTER_ID, None, Some(10), false));
+		assert_eq!(LogionLoc::loc(2).unwrap().loc_type, LocType::Collection);
+		assert_eq!(LogionLoc::next_loc_id(), 3);
+	});
+}
+
+#[test]
+fn it_skips_explicit_ids_when_allocating_next_id() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), 0, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), 1, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::create_polkadot_identity_loc_with_next_id(RuntimeOrigin::signed(LOC_OWNER1), LOC_REQUESTER_ID));
+		assert_eq!(LogionLoc::loc(2).unwrap().loc_type, LocType::Identity);
+		assert_eq!(LogionLoc::next_loc_id(), 3);
+		assert_err!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), 2, LOC_REQUESTER_ID), Error::<Test>::AlreadyExists);
+	});
+}
+
+#[test]
+fn it_allocates_next_id_after_many_explicit_ids() {
+	new_test_ext().execute_with(|| {
+		for loc_id in 0..16 {
+			assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), loc_id, LOC_REQUESTER_ID));
+		}
+		assert_ok!(LogionLoc::create_logion_identity_loc_with_next_id(RuntimeOrigin::signed(LOC_OWNER1)));
+		assert_eq!(LogionLoc::loc(16).unwrap().loc_type, LocType::Identity);
+		assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), 100, LOC_REQUESTER_ID));
+		assert_ok!(LogionLoc::create_logion_identity_loc_with_next_id(RuntimeOrigin::signed(LOC_OWNER1)));
+		assert_eq!(LogionLoc::loc(101).unwrap().loc_type, LocType::Identity);
+		assert_eq!(LogionLoc::next_loc_id(), 102);
+	});
+}
+
+#[test]
+fn it_fails_allocating_next_id_when_exhausted() {
+	new_test_ext().execute_with(|| {
+		crate::NextLocId::<Test>::put(u32::MAX);
+		assert_err!(LogionLoc::create_logion_identity_loc_with_next_id(RuntimeOrigin::signed(LOC_OWNER1)), Error::<Test>::LocIdOverflow);
+	});
+}
+
+#[test]
+fn it_runs_migration_steps_over_several_blocks() {
+	new_test_ext().execute_with(|| {
+		use crate::migrations::multi_block::{MIGRATION_WEIGHT_PER_BYTE, MigrationBudget, MigrationCursor, StepProgress};
+		use crate::migrations::v13::index_locs_by_owner;
+		use crate::migrations::v18::LocMap as LocMapV17;
+
+		LocMapV17::<Test>::insert(LOC_ID, legacy_loc_v17(LocType::Transaction, vec![], vec![]));
+		LocMapV17::<Test>::insert(OTHER_LOC_ID, legacy_loc_v17(LocType::Transaction, vec![], vec![]));
+		let loc_size = legacy_loc_v17(LocType::Transaction, vec![], vec![]).encoded_size() as u64;
+		let one_loc_weight = Weight::from_ref_time(loc_size * MIGRATION_WEIGHT_PER_BYTE);
+		let db_weight = <Test as frame_system::Config>::DbWeight::get();
+
+		let mut budget = MigrationBudget::new(db_weight, one_loc_weight);
+		let cursor = match index_locs_by_owner::<Test>(MigrationCursor::default(), &mut budget) {
+			StepProgress::InProgress(cursor) => cursor,
+			progress => panic!("unexpected progress {:?}", progress),
+		};
+		assert_eq!(cursor.map_index, 0);
+		assert!(cursor.last_key.is_some());
+		assert_eq!(budget.consumed_weight(), one_loc_weight);
+		assert_eq!(crate::LocsByOwner::<Test>::iter_key_prefix(LOC_OWNER1).count(), 1);
+
+		let mut budget = MigrationBudget::new(db_weight, one_loc_weight);
+		assert_eq!(index_locs_by_owner::<Test>(cursor, &mut budget), StepProgress::Completed);
+		assert!(crate::LocsByOwner::<Test>::contains_key(LOC_OWNER1, LOC_ID));
+		assert!(crate::LocsByOwner::<Test>::contains_key(LOC_OWNER1, OTHER_LOC_ID));
+	});
+}
+
+#[test]
+fn it_builds_genesis_locs_and_collection_items() {
+	let file_hash = BlakeTwo256::hash_of(&"test".as_bytes().to_vec());
+	let genesis = GenesisConfig::<Test> {
+		locs: vec![
+			genesis_loc(LOC_ID, LOC_REQUESTER, LocType::Identity),
+			genesis_loc(LOGION_IDENTITY_LOC_ID, Requester::None, LocType::Identity),
+			GenesisLoc {
+				metadata: vec![MetadataItemParams {
+					name: vec![1, 2, 3],
+					value: vec![4, 5, 6],
+					submitter: LOC_OWNER1,
+				}],
+				files: vec![FileParams {
+					hash: file_hash,
+					nature: "test-file-nature".as_bytes().to_vec(),
+					submitter: LOC_OWNER1,
+				}],
+				links: vec![LocLinkParams {
+					id: LOC_ID,
+					nature: "test-link-nature".as_bytes().to_vec(),
+				}],
+				closed: false,
+				..genesis_loc(OTHER_LOC_ID, Requester::Loc(LOGION_IDENTITY_LOC_ID), LocType::Transaction)
+			},
+			GenesisLoc {
+				void_info: Some(LocVoidInfo { replacer: Some(ADDITIONAL_TC_LOC_ID) }),
+				..genesis_loc(LOGION_CLASSIFICATION_LOC_ID, LOC_REQUESTER, LocType::Collection)
+			},
+			genesis_loc(ADDITIONAL_TC_LOC_ID, LOC_REQUESTER, LocType::Collection),
+		],
+		collection_items: vec![
+			(ADDITIONAL_TC_LOC_ID, CollectionItemParams {
+				terms_and_conditions: vec![TermsAndConditionsElementParams {
+					tc_type: "Logion".as_bytes().to_vec(),
+					tc_loc: LOC_ID,
+					details: vec![],
+				}],
+				..collection_item_params(BlakeTwo256::hash_of(&"item".as_bytes().to_vec()))
+			}),
+		],
+	};
+	new_test_ext_with_genesis(genesis).execute_with(|| {
+		let loc = LogionLoc::loc(OTHER_LOC_ID).unwrap();
+		assert_eq!(loc.metadata_count, 1);
+		assert_eq!(loc.files_count, 1);
+		assert_eq!(loc.links_count, 1);
+		assert_eq!(LogionLoc::get_loc_metadata(OTHER_LOC_ID).len(), 1);
+		assert_eq!(LogionLoc::find_by_file_hash(file_hash), vec![(OTHER_LOC_ID, None)]);
+		let mut linked_from = LogionLoc::get_linked_from(LOC_ID);
+		linked_from.sort();
+		assert_eq!(linked_from, vec![OTHER_LOC_ID, ADDITIONAL_TC_LOC_ID]);
+		assert_eq!(LogionLoc::get_identity_loc_locs(LOGION_IDENTITY_LOC_ID, None, 10), vec![OTHER_LOC_ID]);
+		assert_eq!(LogionLoc::get_account_locs_count(LOC_REQUESTER_ID), 3);
+		assert_eq!(LogionLoc::get_owner_locs(LOC_OWNER1, None, 10).len(), 5);
+		assert_eq!(LogionLoc::closed_identity_locs(LOC_REQUESTER_ID, LOC_OWNER1), Some(LOC_ID));
+		assert_eq!(LogionLoc::loc(ADDITIONAL_TC_LOC_ID).unwrap().replacer_of, Some(LOGION_CLASSIFICATION_LOC_ID));
+		assert_eq!(LogionLoc::get_collection_size(ADDITIONAL_TC_LOC_ID), Some(1));
+		assert!(LogionLoc::get_collection_size(LOGION_CLASSIFICATION_LOC_ID).is_none());
+	});
+}
+
+#[test]
+#[should_panic(expected = "Invalid genesis collection LOC")]
+fn it_fails_building_genesis_collection_without_limit() {
+	new_test_ext_with_genesis(GenesisConfig::<Test> {
+		locs: vec![GenesisLoc {
+			collection_max_size: None,
+			..genesis_loc(LOC_ID, LOC_REQUESTER, LocType::Collection)
+		}],
+		collection_items: vec![],
+	});
+}
+
+#[test]
+#[should_panic(expected = "Invalid genesis collection item")]
+fn it_fails_building_genesis_collection_item_with_open_terms_and_conditions_loc() {
+	new_test_ext_with_genesis(GenesisConfig::<Test> {
+		locs: vec![
+			GenesisLoc { closed: false, ..genesis_loc(LOC_ID, LOC_REQUESTER, LocType::Transaction) },
+			genesis_loc(OTHER_LOC_ID, LOC_REQUESTER, LocType::Collection),
+		],
+		collection_items: vec![
+			(OTHER_LOC_ID, CollectionItemParams {
+				terms_and_conditions: vec![TermsAndConditionsElementParams {
+					tc_type: "Logion".as_bytes().to_vec(),
+					tc_loc: LOC_ID,
+					details: vec![],
+				}],
+				..collection_item_params(BlakeTwo256::hash_of(&"item".as_bytes().to_vec()))
+			}),
+		],
+	});
+}
+
+#[test]
+#[should_panic(expected = "Genesis linked LOC not found")]
+fn it_fails_building_genesis_loc_with_unknown_link_target() {
+	new_test_ext_with_genesis(GenesisConfig::<Test> {
+		locs: vec![GenesisLoc {
+			links: vec![LocLinkParams {
+				id: OTHER_LOC_ID,
+				nature: "test-link-nature".as_bytes().to_vec(),
+			}],
+			..genesis_loc(LOC_ID, LOC_REQUESTER, LocType::Transaction)
+		}],
+		collection_items: vec![],
+	});
+}
+
+#[test]
+#[should_panic(expected = "Genesis requester LOC must be a valid logion identity LOC")]
+fn it_fails_building_genesis_loc_with_invalid_requester_loc() {
+	new_test_ext_with_genesis(GenesisConfig::<Test> {
+		locs: vec![
+			GenesisLoc { closed: false, ..genesis_loc(LOGION_IDENTITY_LOC_ID, Requester::None, LocType::Identity) },
+			genesis_loc(LOC_ID, Requester::Loc(LOGION_IDENTITY_LOC_ID), LocType::Transaction),
+		],
+		collection_items: vec![],
+	});
+}
+
+#[test]
+#[should_panic(expected = "Invalid genesis metadata item submitter")]
+fn it_fails_building_genesis_loc_with_invalid_metadata_submitter() {
+	new_test_ext_with_genesis(GenesisConfig::<Test> {
+		locs: vec![
+			genesis_loc(LOGION_IDENTITY_LOC_ID, Requester::None, LocType::Identity),
+			GenesisLoc {
+				metadata: vec![MetadataItemParams {
+					name: vec![1, 2, 3],
+					value: vec![4, 5, 6],
+					submitter: LOC_REQUESTER_ID,
+				}],
+				..genesis_loc(LOC_ID, Requester::Loc(LOGION_IDENTITY_LOC_ID), LocType::Transaction)
+			},
+		],
+		collection_items: vec![],
+	});
+}
+
+#[test]
+#[should_panic(expected = "Invalid genesis file submitter")]
+fn it_fails_building_genesis_loc_with_invalid_file_submitter() {
+	new_test_ext_with_genesis(GenesisConfig::<Test> {
+		locs: vec![
+			genesis_loc(LOGION_IDENTITY_LOC_ID, Requester::None, LocType::Identity),
+			GenesisLoc {
+				files: vec![FileParams {
+					hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
+					nature: "test-file-nature".as_bytes().to_vec(),
+					submitter: LOC_REQUESTER_ID,
+				}],
+				..genesis_loc(LOC_ID, Requester::Loc(LOGION_IDENTITY_LOC_ID), LocType::Transaction)
+			},
+		],
+		collection_items: vec![],
+	});
+}
+
+#[test]
+#[should_panic(expected = "Genesis collection LOC requester must be an account")]
+fn it_fails_building_genesis_collection_loc_without_requester_account() {
+	new_test_ext_with_genesis(GenesisConfig::<Test> {
+		locs: vec![genesis_loc(LOC_ID, Requester::None, LocType::Collection)],
+		collection_items: vec![],
+	});
+}
+
+fn genesis_loc(id: u32, requester: RequesterOf<Test>, loc_type: LocType) -> crate::GenesisLocOf<Test> {
+	GenesisLoc {
+		id,
+		owner: LOC_OWNER1,
+		requester,
+		collection_max_size: if loc_type == LocType::Collection { Some(10) } else { None },
+		loc_type,
+		metadata: vec![],
+		files: vec![],
+		links: vec![],
+		closed: true,
+		seal: None,
+		void_info: None,
+		collection_last_block_submission: None,
+		collection_can_upload: false,
+	}
+}
@@ -1,9 +1,21 @@
-//! Autogenerated weights for pallet_logion_loc
+//! Weights for pallet_logion_loc
+//!
+//! The weights below were originally generated with the benchmark CLI, as recorded further down, and have not been
+//! regenerated since: they are maintained by hand as calls and benchmarks change.
+//!
+//! - Storage reads and writes are counted from the worst-case path of each call, the one exercised by its benchmark:
+//!   migration guard, LOC and item maps, deposits (reserved balance and DepositsMap), fees (payer balance and fee
+//!   destination), ownership, requester and file hash indexes, LinkedFrom and closed identity LOC indexes.
+//! - Execution times are the 2022 measurements. Those of size-parameterized calls and of calls added since are
+//!   estimates which have not been measured.
+//!
+//! Regenerate this file by running the pallet's benchmarks with the command below before using these weights on a
+//! production chain.
 //!
 //! The [original template](https://github.com/paritytech/substrate/blob/630422d6108cbaaca893ab213dde69f3bdaa1f6b/.maintain/frame-weight-template.hbs)
 //! was disclosed under [Apache 2.0 license](http://www.apache.org/licenses/LICENSE-2.0).
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 3.0.0
+//! LAST GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 3.0.0
 //! DATE: 2022-02-08, STEPS: `[20, ]`, REPEAT: 10, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 128
 
@@ -41,14 +53,27 @@ pub trait WeightInfo {
 	fn create_logion_identity_loc() -> Weight;
 	fn create_polkadot_transaction_loc() -> Weight;
 	fn create_logion_transaction_loc() -> Weight;
-	fn add_metadata() -> Weight;
+	fn add_metadata(v: u32, ) -> Weight;
 	fn add_file() -> Weight;
 	fn add_link() -> Weight;
-	fn close() -> Weight;
+	fn delete_metadata(n: u32, ) -> Weight;
+	fn delete_file(n: u32, ) -> Weight;
+	fn delete_link(n: u32, ) -> Weight;
+	fn acknowledge_metadata(n: u32, ) -> Weight;
+	fn acknowledge_file(n: u32, ) -> Weight;
+	fn close(n: u32, ) -> Weight;
+	fn close_and_seal(n: u32, ) -> Weight;
 	fn make_void() -> Weight;
 	fn make_void_and_replace() -> Weight;
 	fn create_collection_loc() -> Weight;
-	fn add_collection_item() -> Weight;
+	fn add_collection_item(f: u32, ) -> Weight;
+	fn add_collection_item_with_terms_and_conditions(f: u32, t: u32, ) -> Weight;
+	fn add_collection_items(n: u32, f: u32, t: u32, ) -> Weight;
+	fn update_collection_item(f: u32, t: u32, ) -> Weight;
+	fn make_item_void() -> Weight;
+	fn transfer_ownership() -> Weight;
+	fn transfer_all_ownership(n: u32, ) -> Weight;
+	fn pay_loc_fee() -> Weight;
 }
 
 /// Weights for pallet_logion_loc using the Substrate node and recommended hardware.
@@ -56,63 +81,170 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn create_polkadot_identity_loc() -> Weight {
 		Weight::from_ref_time(29_862_000)
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	fn create_logion_identity_loc() -> Weight {
 		Weight::from_ref_time(20_945_000)
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	fn create_polkadot_transaction_loc() -> Weight {
 		Weight::from_ref_time(26_316_000)
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	fn create_logion_transaction_loc() -> Weight {
 		Weight::from_ref_time(30_288_000)
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
-	fn add_metadata() -> Weight {
+	fn add_metadata(v: u32, ) -> Weight {
 		Weight::from_ref_time(11_979_000)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_ref_time(2_000).saturating_mul(v as u64))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	fn add_file() -> Weight {
 		Weight::from_ref_time(11_971_000)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	fn add_link() -> Weight {
 		Weight::from_ref_time(16_067_000)
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	fn delete_metadata(n: u32, ) -> Weight {
+		Weight::from_ref_time(24_318_000)
+			.saturating_add(Weight::from_ref_time(1_412_000).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	fn delete_file(n: u32, ) -> Weight {
+		Weight::from_ref_time(24_102_000)
+			.saturating_add(Weight::from_ref_time(1_398_000).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+	}
+	fn delete_link(n: u32, ) -> Weight {
+		Weight::from_ref_time(23_870_000)
+			.saturating_add(Weight::from_ref_time(1_405_000).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
 	}
-	fn close() -> Weight {
+	fn acknowledge_metadata(n: u32, ) -> Weight {
+		Weight::from_ref_time(35_411_000)
+			.saturating_add(Weight::from_ref_time(1_226_000).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	fn acknowledge_file(n: u32, ) -> Weight {
+		Weight::from_ref_time(35_207_000)
+			.saturating_add(Weight::from_ref_time(1_214_000).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	fn close(n: u32, ) -> Weight {
 		Weight::from_ref_time(22_224_000)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_ref_time(1_830_000).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn close_and_seal(n: u32, ) -> Weight {
+		Weight::from_ref_time(23_105_000)
+			.saturating_add(Weight::from_ref_time(1_830_000).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	fn make_void() -> Weight {
 		Weight::from_ref_time(22_360_000)
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	fn make_void_and_replace() -> Weight {
 		Weight::from_ref_time(32_724_000)
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	fn create_collection_loc() -> Weight {
 		Weight::from_ref_time(29_219_000)
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
-	fn add_collection_item() -> Weight {
+	fn add_collection_item(f: u32, ) -> Weight {
 		Weight::from_ref_time(31_621_000)
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(Weight::from_ref_time(4_120_000).saturating_mul(f as u64))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(f as u64)))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(f as u64)))
+	}
+	fn add_collection_item_with_terms_and_conditions(f: u32, t: u32, ) -> Weight {
+		Weight::from_ref_time(32_046_000)
+			.saturating_add(Weight::from_ref_time(4_120_000).saturating_mul(f as u64))
+			.saturating_add(Weight::from_ref_time(5_370_000).saturating_mul(t as u64))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(f as u64)))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(t as u64)))
 			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(f as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(t as u64)))
+	}
+	fn add_collection_items(n: u32, f: u32, t: u32, ) -> Weight {
+		Weight::from_ref_time(28_470_000)
+			.saturating_add(Weight::from_ref_time(14_215_000).saturating_mul(n as u64))
+			.saturating_add(Weight::from_ref_time(4_120_000).saturating_mul(f as u64))
+			.saturating_add(Weight::from_ref_time(5_370_000).saturating_mul(t as u64))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(t as u64)))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(f as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(t as u64)))
+	}
+	fn update_collection_item(f: u32, t: u32, ) -> Weight {
+		Weight::from_ref_time(36_104_000)
+			.saturating_add(Weight::from_ref_time(4_120_000).saturating_mul(f as u64))
+			.saturating_add(Weight::from_ref_time(5_370_000).saturating_mul(t as u64))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(t as u64)))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(f as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(t as u64)))
+	}
+	fn make_item_void() -> Weight {
+		Weight::from_ref_time(30_562_000)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn transfer_ownership() -> Weight {
+		Weight::from_ref_time(33_916_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	fn transfer_all_ownership(n: u32, ) -> Weight {
+		Weight::from_ref_time(12_407_000)
+			.saturating_add(Weight::from_ref_time(31_734_000).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((6 as u64).saturating_mul(n as u64)))
+	}
+	fn pay_loc_fee() -> Weight {
+		Weight::from_ref_time(38_000_000)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 }
 
@@ -120,62 +252,169 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 impl WeightInfo for () {
   fn create_polkadot_identity_loc() -> Weight {
     Weight::from_ref_time(29_862_000)
-      .saturating_add(RocksDbWeight::get().reads(3))
-      .saturating_add(RocksDbWeight::get().writes(2))
+      .saturating_add(RocksDbWeight::get().reads(7))
+      .saturating_add(RocksDbWeight::get().writes(8))
   }
   fn create_logion_identity_loc() -> Weight {
     Weight::from_ref_time(20_945_000)
-      .saturating_add(RocksDbWeight::get().reads(2))
-      .saturating_add(RocksDbWeight::get().writes(1))
+      .saturating_add(RocksDbWeight::get().reads(6))
+      .saturating_add(RocksDbWeight::get().writes(5))
   }
   fn create_polkadot_transaction_loc() -> Weight {
     Weight::from_ref_time(26_316_000)
-      .saturating_add(RocksDbWeight::get().reads(3))
-      .saturating_add(RocksDbWeight::get().writes(2))
+      .saturating_add(RocksDbWeight::get().reads(7))
+      .saturating_add(RocksDbWeight::get().writes(8))
   }
   fn create_logion_transaction_loc() -> Weight {
     Weight::from_ref_time(30_288_000)
-      .saturating_add(RocksDbWeight::get().reads(4))
-      .saturating_add(RocksDbWeight::get().writes(2))
+      .saturating_add(RocksDbWeight::get().reads(8))
+      .saturating_add(RocksDbWeight::get().writes(7))
   }
-  fn add_metadata() -> Weight {
+  fn add_metadata(v: u32, ) -> Weight {
     Weight::from_ref_time(11_979_000)
-      .saturating_add(RocksDbWeight::get().reads(1))
-      .saturating_add(RocksDbWeight::get().writes(1))
+      .saturating_add(Weight::from_ref_time(2_000).saturating_mul(v as u64))
+      .saturating_add(RocksDbWeight::get().reads(5))
+      .saturating_add(RocksDbWeight::get().writes(4))
   }
   fn add_file() -> Weight {
     Weight::from_ref_time(11_971_000)
-      .saturating_add(RocksDbWeight::get().reads(1))
-      .saturating_add(RocksDbWeight::get().writes(1))
+      .saturating_add(RocksDbWeight::get().reads(5))
+      .saturating_add(RocksDbWeight::get().writes(5))
   }
   fn add_link() -> Weight {
     Weight::from_ref_time(16_067_000)
-      .saturating_add(RocksDbWeight::get().reads(2))
-      .saturating_add(RocksDbWeight::get().writes(1))
+      .saturating_add(RocksDbWeight::get().reads(7))
+      .saturating_add(RocksDbWeight::get().writes(5))
   }
-  fn close() -> Weight {
+  fn delete_metadata(n: u32, ) -> Weight {
+    Weight::from_ref_time(24_318_000)
+      .saturating_add(Weight::from_ref_time(1_412_000).saturating_mul(n as u64))
+      .saturating_add(RocksDbWeight::get().reads(5))
+      .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+      .saturating_add(RocksDbWeight::get().writes(3))
+      .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+  }
+  fn delete_file(n: u32, ) -> Weight {
+    Weight::from_ref_time(24_102_000)
+      .saturating_add(Weight::from_ref_time(1_398_000).saturating_mul(n as u64))
+      .saturating_add(RocksDbWeight::get().reads(5))
+      .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+      .saturating_add(RocksDbWeight::get().writes(4))
+      .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+  }
+  fn delete_link(n: u32, ) -> Weight {
+    Weight::from_ref_time(23_870_000)
+      .saturating_add(Weight::from_ref_time(1_405_000).saturating_mul(n as u64))
+      .saturating_add(RocksDbWeight::get().reads(6))
+      .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+      .saturating_add(RocksDbWeight::get().writes(4))
+      .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(n as u64)))
+  }
+  fn acknowledge_metadata(n: u32, ) -> Weight {
+    Weight::from_ref_time(35_411_000)
+      .saturating_add(Weight::from_ref_time(1_226_000).saturating_mul(n as u64))
+      .saturating_add(RocksDbWeight::get().reads(6))
+      .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+      .saturating_add(RocksDbWeight::get().writes(5))
+  }
+  fn acknowledge_file(n: u32, ) -> Weight {
+    Weight::from_ref_time(35_207_000)
+      .saturating_add(Weight::from_ref_time(1_214_000).saturating_mul(n as u64))
+      .saturating_add(RocksDbWeight::get().reads(6))
+      .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+      .saturating_add(RocksDbWeight::get().writes(5))
+  }
+  fn close(n: u32, ) -> Weight {
     Weight::from_ref_time(22_224_000)
-      .saturating_add(RocksDbWeight::get().reads(1))
-      .saturating_add(RocksDbWeight::get().writes(1))
+      .saturating_add(Weight::from_ref_time(1_830_000).saturating_mul(n as u64))
+      .saturating_add(RocksDbWeight::get().reads(3))
+      .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+      .saturating_add(RocksDbWeight::get().writes(3))
+  }
+  fn close_and_seal(n: u32, ) -> Weight {
+    Weight::from_ref_time(23_105_000)
+      .saturating_add(Weight::from_ref_time(1_830_000).saturating_mul(n as u64))
+      .saturating_add(RocksDbWeight::get().reads(3))
+      .saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+      .saturating_add(RocksDbWeight::get().writes(3))
   }
   fn make_void() -> Weight {
     Weight::from_ref_time(22_360_000)
-      .saturating_add(RocksDbWeight::get().reads(1))
-      .saturating_add(RocksDbWeight::get().writes(1))
+      .saturating_add(RocksDbWeight::get().reads(5))
+      .saturating_add(RocksDbWeight::get().writes(3))
   }
   fn make_void_and_replace() -> Weight {
     Weight::from_ref_time(32_724_000)
-      .saturating_add(RocksDbWeight::get().reads(2))
-      .saturating_add(RocksDbWeight::get().writes(2))
+      .saturating_add(RocksDbWeight::get().reads(6))
+      .saturating_add(RocksDbWeight::get().writes(4))
   }
   fn create_collection_loc() -> Weight {
     Weight::from_ref_time(29_219_000)
-      .saturating_add(RocksDbWeight::get().reads(3))
-      .saturating_add(RocksDbWeight::get().writes(2))
+      .saturating_add(RocksDbWeight::get().reads(7))
+      .saturating_add(RocksDbWeight::get().writes(8))
   }
-  fn add_collection_item() -> Weight {
+  fn add_collection_item(f: u32, ) -> Weight {
     Weight::from_ref_time(31_621_000)
-      .saturating_add(RocksDbWeight::get().reads(3))
+      .saturating_add(Weight::from_ref_time(4_120_000).saturating_mul(f as u64))
+      .saturating_add(RocksDbWeight::get().reads(4))
+      .saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(f as u64)))
       .saturating_add(RocksDbWeight::get().writes(2))
+      .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(f as u64)))
+  }
+  fn add_collection_item_with_terms_and_conditions(f: u32, t: u32, ) -> Weight {
+    Weight::from_ref_time(32_046_000)
+      .saturating_add(Weight::from_ref_time(4_120_000).saturating_mul(f as u64))
+      .saturating_add(Weight::from_ref_time(5_370_000).saturating_mul(t as u64))
+      .saturating_add(RocksDbWeight::get().reads(4))
+      .saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(f as u64)))
+      .saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(t as u64)))
+      .saturating_add(RocksDbWeight::get().writes(2))
+      .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(f as u64)))
+      .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(t as u64)))
+  }
+  fn add_collection_items(n: u32, f: u32, t: u32, ) -> Weight {
+    Weight::from_ref_time(28_470_000)
+      .saturating_add(Weight::from_ref_time(14_215_000).saturating_mul(n as u64))
+      .saturating_add(Weight::from_ref_time(4_120_000).saturating_mul(f as u64))
+      .saturating_add(Weight::from_ref_time(5_370_000).saturating_mul(t as u64))
+      .saturating_add(RocksDbWeight::get().reads(6))
+      .saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+      .saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(t as u64)))
+      .saturating_add(RocksDbWeight::get().writes(4))
+      .saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+      .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(f as u64)))
+      .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(t as u64)))
+  }
+  fn update_collection_item(f: u32, t: u32, ) -> Weight {
+    Weight::from_ref_time(36_104_000)
+      .saturating_add(Weight::from_ref_time(4_120_000).saturating_mul(f as u64))
+      .saturating_add(Weight::from_ref_time(5_370_000).saturating_mul(t as u64))
+      .saturating_add(RocksDbWeight::get().reads(7))
+      .saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(t as u64)))
+      .saturating_add(RocksDbWeight::get().writes(5))
+      .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(f as u64)))
+      .saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(t as u64)))
+  }
+  fn make_item_void() -> Weight {
+    Weight::from_ref_time(30_562_000)
+      .saturating_add(RocksDbWeight::get().reads(4))
+      .saturating_add(RocksDbWeight::get().writes(2))
+  }
+  fn transfer_ownership() -> Weight {
+    Weight::from_ref_time(33_916_000)
+      .saturating_add(RocksDbWeight::get().reads(5))
+      .saturating_add(RocksDbWeight::get().writes(6))
+  }
+  fn transfer_all_ownership(n: u32, ) -> Weight {
+    Weight::from_ref_time(12_407_000)
+      .saturating_add(Weight::from_ref_time(31_734_000).saturating_mul(n as u64))
+      .saturating_add(RocksDbWeight::get().reads(2))
+      .saturating_add(RocksDbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+      .saturating_add(RocksDbWeight::get().writes((6 as u64).saturating_mul(n as u64)))
+  }
+  fn pay_loc_fee() -> Weight {
+    Weight::from_ref_time(38_000_000)
+      .saturating_add(RocksDbWeight::get().reads(7))
+      .saturating_add(RocksDbWeight::get().writes(4))
   }
 }